@@ -0,0 +1,77 @@
+//! Guarded transmute wrappers for types implementing other crates' marker traits.
+//!
+//! Used by the `bytemuck` and `zerocopy` modules, so they only differ in the bounds placed on each function.
+
+
+/// Define the `guarded_transmute_pod_*()` mirrors in the calling module,
+/// with `value` bounding single values, `view` slice views, `vec` vectors, and `to_bytes` and `to_bytes_mut` the reverse views.
+macro_rules! pod_bridge {
+    (value: [$($value:tt)+],
+     view: [$($view:tt)+],
+     vec: [$($vec:tt)+],
+     to_bytes: [$($to_bytes:tt)+],
+     to_bytes_mut: [$($to_bytes_mut:tt)+]) => {
+        use $crate::{Error, guarded_transmute_many_permissive, guarded_transmute_many_pedantic, guarded_transmute_pedantic, guarded_transmute_many,
+                     guarded_transmute};
+        #[cfg(feature = "alloc")]
+        use $crate::{VecError, guarded_transmute_vec_permissive, guarded_transmute_vec_pedantic, guarded_transmute_vec};
+        #[cfg(feature = "alloc")]
+        use alloc::vec::Vec;
+        use core::mem::size_of_val;
+        use core::slice;
+
+
+        /// Transmute a byte slice into a single instance of a type, like `guarded_transmute_pod()`.
+        pub fn guarded_transmute_pod<T: $($value)+>(bytes: &[u8]) -> Result<T, Error> {
+            unsafe { guarded_transmute(bytes) }
+        }
+
+        /// Transmute a byte slice into a single instance of a type, like `guarded_transmute_pod_pedantic()`.
+        pub fn guarded_transmute_pod_pedantic<T: $($value)+>(bytes: &[u8]) -> Result<T, Error> {
+            unsafe { guarded_transmute_pedantic(bytes) }
+        }
+
+        /// View a byte slice as a slice of a type, like `guarded_transmute_pod_many()`.
+        pub fn guarded_transmute_pod_many<T: $($view)+>(bytes: &[u8]) -> Result<&[T], Error> {
+            unsafe { guarded_transmute_many(bytes) }
+        }
+
+        /// View a byte slice as a slice of a type, like `guarded_transmute_pod_many_permissive()`.
+        pub fn guarded_transmute_pod_many_permissive<T: $($view)+>(bytes: &[u8]) -> &[T] {
+            unsafe { guarded_transmute_many_permissive(bytes) }
+        }
+
+        /// View a byte slice as a slice of a type, like `guarded_transmute_pod_many_pedantic()`.
+        pub fn guarded_transmute_pod_many_pedantic<T: $($view)+>(bytes: &[u8]) -> Result<&[T], Error> {
+            unsafe { guarded_transmute_many_pedantic(bytes) }
+        }
+
+        /// Transform a byte vector into a vector of a type, like `guarded_transmute_pod_vec()`.
+        #[cfg(feature = "alloc")]
+        pub fn guarded_transmute_pod_vec<T: $($vec)+>(bytes: Vec<u8>) -> Result<Vec<T>, VecError> {
+            unsafe { guarded_transmute_vec(bytes) }
+        }
+
+        /// Transform a byte vector into a vector of a type, like `guarded_transmute_pod_vec_permissive()`.
+        #[cfg(feature = "alloc")]
+        pub fn guarded_transmute_pod_vec_permissive<T: $($vec)+>(bytes: Vec<u8>) -> Vec<T> {
+            unsafe { guarded_transmute_vec_permissive(bytes) }
+        }
+
+        /// Transform a byte vector into a vector of a type, like `guarded_transmute_pod_vec_pedantic()`.
+        #[cfg(feature = "alloc")]
+        pub fn guarded_transmute_pod_vec_pedantic<T: $($vec)+>(bytes: Vec<u8>) -> Result<Vec<T>, VecError> {
+            unsafe { guarded_transmute_vec_pedantic(bytes) }
+        }
+
+        /// View a slice of a type as its underlying bytes, like `guarded_transmute_to_bytes_pod_many()`.
+        pub fn guarded_transmute_to_bytes_pod_many<T: $($to_bytes)+>(from: &[T]) -> &[u8] {
+            unsafe { slice::from_raw_parts(from.as_ptr() as *const u8, size_of_val(from)) }
+        }
+
+        /// View a mutable slice of a type as its underlying bytes, like `guarded_transmute_to_bytes_pod_many_mut()`.
+        pub fn guarded_transmute_to_bytes_pod_many_mut<T: $($to_bytes_mut)+>(from: &mut [T]) -> &mut [u8] {
+            unsafe { slice::from_raw_parts_mut(from.as_mut_ptr() as *mut u8, size_of_val(from)) }
+        }
+    };
+}
@@ -0,0 +1,117 @@
+//! Typed views of `bytes` buffers as POD.
+//!
+//! Available with the `bytes` feature.
+//!
+//! The buffers must be suitably aligned for `T` and contain a whole, non-zero amount of `T`s,
+//! as with `guarded_transmute_pod_many_pedantic()`.
+
+
+use self::super::{PodTransmutable, Error, guarded_transmute_pod_many_pedantic};
+use bytes_crate::{BytesMut, Bytes};
+use core::marker::PhantomData;
+use core::{fmt, slice};
+use core::ops::Deref;
+
+
+/// A reference-counted slice of POD, backed by `Bytes`.
+///
+/// Cloning it is cheap, and doesn't copy the data.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # extern crate bytes;
+/// # use safe_transmute::bytes::PodBytes;
+/// # use bytes::BytesMut;
+/// # fn main() {
+/// // Overallocate, and split off the head before the first aligned byte
+/// let mut bytes = BytesMut::zeroed(8 + 3);
+/// let head = bytes.as_ptr().align_offset(4);
+/// let mut bytes = bytes.split_off(head);
+/// bytes.truncate(8);
+/// bytes.copy_from_slice(&[1u32.to_ne_bytes(), 2u32.to_ne_bytes()].concat());
+///
+/// let values = PodBytes::<u32>::new(bytes.freeze()).unwrap();
+/// assert_eq!(&values[..], &[1, 2]);
+///
+/// let copy = values.clone();
+/// assert_eq!(copy.as_ptr(), values.as_ptr());
+/// # }
+/// ```
+pub struct PodBytes<T: PodTransmutable> {
+    bytes: Bytes,
+    len: usize,
+    _type: PhantomData<T>,
+}
+
+impl<T: PodTransmutable> PodBytes<T> {
+    /// View the specified bytes as a slice of POD.
+    pub fn new(bytes: Bytes) -> Result<PodBytes<T>, Error> {
+        Ok(PodBytes {
+            len: guarded_transmute_pod_many_pedantic::<T>(&bytes)?.len(),
+            bytes,
+            _type: PhantomData,
+        })
+    }
+
+    /// Get the underlying bytes.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Get the underlying bytes back.
+    pub fn into_inner(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl<T: PodTransmutable> Deref for PodBytes<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.bytes.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T: PodTransmutable> Clone for PodBytes<T> {
+    fn clone(&self) -> PodBytes<T> {
+        PodBytes {
+            bytes: self.bytes.clone(),
+            len: self.len,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<T: PodTransmutable + fmt::Debug> fmt::Debug for PodBytes<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+
+/// View a mutable byte buffer as a mutable slice of POD.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # extern crate bytes;
+/// # use safe_transmute::bytes::view_mut;
+/// # use bytes::BytesMut;
+/// # fn main() {
+/// // Overallocate, and split off the head before the first aligned byte
+/// let mut bytes = BytesMut::zeroed(8 + 1);
+/// let head = bytes.as_ptr().align_offset(2);
+/// let mut bytes = bytes.split_off(head);
+/// bytes.truncate(8);
+///
+/// view_mut::<u16>(&mut bytes).unwrap()[1] = 0x0102;
+/// assert_eq!(&bytes[2..4], &0x0102u16.to_ne_bytes());
+/// # }
+/// ```
+pub fn view_mut<T: PodTransmutable>(bytes: &mut BytesMut) -> Result<&mut [T], Error> {
+    let len = guarded_transmute_pod_many_pedantic::<T>(bytes)?.len();
+    Ok(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, len) })
+}
@@ -0,0 +1,374 @@
+//! A self-describing container format for arrays of POD.
+//!
+//! A container is a 64-byte header followed by the elements in native byte order.
+//! The header records the element size, alignment, byte order and layout fingerprint,
+//! so data written by one build is refused by another in which the element type's layout differs,
+//! instead of being silently misinterpreted.
+//!
+//! All header fields are little-endian:
+//!
+//! | Offset | Size | Field                                         |
+//! |--------|------|-----------------------------------------------|
+//! | 0      | 8    | Magic string `"\x7FSTPOD\r\n"`                |
+//! | 8      | 2    | Format version, currently `1`                 |
+//! | 10     | 1    | Element byte order, `0` for little, `1` for big |
+//! | 11     | 1    | Reserved, `0`                                 |
+//! | 12     | 4    | Element size                                  |
+//! | 16     | 4    | Element alignment                             |
+//! | 20     | 4    | Reserved, `0`                                 |
+//! | 24     | 8    | Element layout fingerprint                    |
+//! | 32     | 8    | Element count                                 |
+//! | 40     | 24   | Reserved, `0`                                 |
+//!
+//! # Examples
+//!
+//! ```
+//! # use safe_transmute::container;
+//! let mut file = vec![];
+//! container::write(&mut file, &[1u32, 2, 3]).unwrap();
+//!
+//! assert_eq!(container::read::<u32>(&mut &file[..]).unwrap(), vec![1, 2, 3]);
+//! assert!(container::read::<i32>(&mut &file[..]).is_err());
+//! ```
+
+
+use std::error::Error as StdError;
+use std::io::{self, ErrorKind, Write, Read};
+use std::mem::{align_of, size_of};
+use std::convert::TryFrom;
+use std::fmt;
+use self::super::{DetailedError, LayoutKind, TypeLayout, Endianness, ErrorReason, Error, PodTransmutable, guarded_transmute_pod_many_pedantic,
+                  guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_vec_pedantic, check_not_zero_sized, read_pod_into};
+
+
+const MAGIC: &[u8; 8] = b"\x7FSTPOD\r\n";
+const VERSION: u16 = 1;
+/// Size of the header, so the elements that follow it are aligned for all but the most exotic types.
+pub const HEADER_SIZE: usize = 64;
+
+
+/// Get a fingerprint of a type's layout.
+///
+/// The fingerprint is a 64-bit FNV-1a hash of the type's `layout()`.
+///
+/// For structs described with `pod_transmutable!()` this covers the size, alignment, and field names, offsets and types,
+/// but not the struct's own name, so it can be renamed or moved freely.
+/// Primitives and other types without a described structure are identified by name, size and alignment.
+///
+/// Names come from `std::any::type_name()`, which isn't guaranteed to be stable across compiler versions,
+/// so containers written by a build with a different compiler may be refused even if the layout is the same.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::container::layout_fingerprint;
+/// assert_eq!(layout_fingerprint::<u32>(), layout_fingerprint::<u32>());
+/// assert_ne!(layout_fingerprint::<u32>(), layout_fingerprint::<i32>());
+/// ```
+pub fn layout_fingerprint<T: PodTransmutable>() -> u64 {
+    let mut hash = Fnv1a::new();
+    hash_layout(&mut hash, &T::layout());
+    hash.finish()
+}
+
+fn hash_layout(hash: &mut Fnv1a, layout: &TypeLayout) {
+    match layout.kind {
+        LayoutKind::Primitive => {
+            hash.write(b"P");
+            hash.write_str(layout.name);
+        }
+        LayoutKind::Array { ref element, len } => {
+            hash.write(b"A");
+            hash.write_usize(len);
+            hash_layout(hash, element);
+        }
+        LayoutKind::Struct { ref fields } => {
+            hash.write(b"S");
+            hash.write_usize(fields.len());
+            for field in fields {
+                hash.write_str(field.name);
+                hash.write_usize(field.offset);
+                hash_layout(hash, &field.layout);
+            }
+        }
+        LayoutKind::Opaque => {
+            hash.write(b"O");
+            hash.write_str(layout.name);
+        }
+    }
+    hash.write_usize(layout.size);
+    hash.write_usize(layout.align);
+}
+
+
+/// A container header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Header {
+    /// The format version.
+    pub version: u16,
+    /// The elements' byte order.
+    pub endianness: Endianness,
+    /// Size of a single element in bytes.
+    pub element_size: u32,
+    /// Alignment of the element type.
+    pub element_alignment: u32,
+    /// The element type's `layout_fingerprint()`.
+    pub layout_fingerprint: u64,
+    /// Amount of elements in the container.
+    pub element_count: u64,
+}
+
+impl Header {
+    /// Get the header for a container of `count` `T`s written by this build.
+    pub fn of<T: PodTransmutable>(count: usize) -> Header {
+        Header {
+            version: VERSION,
+            endianness: Endianness::NATIVE,
+            element_size: size_of::<T>() as u32,
+            element_alignment: align_of::<T>() as u32,
+            layout_fingerprint: layout_fingerprint::<T>(),
+            element_count: count as u64,
+        }
+    }
+
+    /// Parse a header from the start of a container.
+    pub fn parse(bytes: &[u8]) -> Result<Header, ContainerError> {
+        if bytes.len() < HEADER_SIZE || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(ContainerError::InvalidMagic);
+        }
+
+        let version = u16::from_le_bytes([bytes[8], bytes[9]]);
+        if version != VERSION {
+            return Err(ContainerError::UnsupportedVersion(version));
+        }
+
+        Ok(Header {
+            version,
+            endianness: match bytes[10] {
+                0 => Endianness::Little,
+                1 => Endianness::Big,
+                _ => return Err(ContainerError::InvalidMagic),
+            },
+            element_size: u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]),
+            element_alignment: u32::from_le_bytes([bytes[16], bytes[17], bytes[18], bytes[19]]),
+            layout_fingerprint: u64::from_le_bytes([bytes[24], bytes[25], bytes[26], bytes[27], bytes[28], bytes[29], bytes[30], bytes[31]]),
+            element_count: u64::from_le_bytes([bytes[32], bytes[33], bytes[34], bytes[35], bytes[36], bytes[37], bytes[38], bytes[39]]),
+        })
+    }
+
+    /// Serialise this header.
+    pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+        let mut ret = [0; HEADER_SIZE];
+        ret[..8].copy_from_slice(MAGIC);
+        ret[8..10].copy_from_slice(&self.version.to_le_bytes());
+        ret[10] = match self.endianness {
+            Endianness::Little => 0,
+            Endianness::Big => 1,
+        };
+        ret[12..16].copy_from_slice(&self.element_size.to_le_bytes());
+        ret[16..20].copy_from_slice(&self.element_alignment.to_le_bytes());
+        ret[24..32].copy_from_slice(&self.layout_fingerprint.to_le_bytes());
+        ret[32..40].copy_from_slice(&self.element_count.to_le_bytes());
+        ret
+    }
+
+    /// Check whether the elements described by this header can be read as `T`s.
+    pub fn check<T: PodTransmutable>(&self) -> Result<(), ContainerError> {
+        let expected = Header::of::<T>(0);
+        let checks = [(LayoutField::Endianness, expected.endianness as u64, self.endianness as u64),
+                      (LayoutField::Size, expected.element_size as u64, self.element_size as u64),
+                      (LayoutField::Alignment, expected.element_alignment as u64, self.element_alignment as u64),
+                      (LayoutField::Fingerprint, expected.layout_fingerprint, self.layout_fingerprint)];
+        for &(field, expected, found) in &checks {
+            if expected != found {
+                return Err(ContainerError::LayoutMismatch {
+                    field,
+                    expected,
+                    found,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+
+/// Part of the element layout recorded in a container header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LayoutField {
+    /// The elements' byte order, as `0` for little-endian and `1` for big-endian.
+    Endianness,
+    /// The element size.
+    Size,
+    /// The element alignment.
+    Alignment,
+    /// The element layout fingerprint.
+    Fingerprint,
+}
+
+/// An error reading a container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerError {
+    /// The data doesn't start with a valid container header.
+    InvalidMagic,
+    /// The container's format version isn't supported.
+    UnsupportedVersion(u16),
+    /// The container's elements were written with a different layout than the requested type's.
+    LayoutMismatch {
+        /// Which part of the layout differs.
+        field: LayoutField,
+        /// The requested type's value.
+        expected: u64,
+        /// The container's value.
+        found: u64,
+    },
+    /// The amount of element data doesn't match the header's element count.
+    CountMismatch {
+        /// The element count in the header.
+        expected: u64,
+        /// The amount of elements in the data.
+        found: u64,
+    },
+    /// The element data can't be viewed as the requested type.
+    Transmute(Error),
+}
+
+impl From<Error> for ContainerError {
+    fn from(err: Error) -> ContainerError {
+        ContainerError::Transmute(err)
+    }
+}
+
+impl StdError for ContainerError {}
+
+impl fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ContainerError::InvalidMagic => write!(f, "Invalid container header"),
+            ContainerError::UnsupportedVersion(version) => write!(f, "Unsupported container version {}", version),
+            ContainerError::LayoutMismatch { field: LayoutField::Fingerprint, expected, found } => {
+                write!(f, "Element layout fingerprint mismatch (expected: {:#018x}, found: {:#018x})", expected, found)
+            }
+            ContainerError::LayoutMismatch { field, expected, found } => write!(f, "Element {:?} mismatch (expected: {}, found: {})", field, expected, found),
+            ContainerError::CountMismatch { expected, found } => write!(f, "Element count mismatch (expected: {}, found: {})", expected, found),
+            ContainerError::Transmute(ref err) => err.fmt(f),
+        }
+    }
+}
+
+
+/// Write a slice of POD as a container.
+pub fn write<T: PodTransmutable>(writer: &mut impl Write, data: &[T]) -> io::Result<()> {
+    writer.write_all(&Header::of::<T>(data.len()).to_bytes())?;
+    writer.write_all(guarded_transmute_to_bytes_pod_many(data))
+}
+
+/// Read a container of `T`s from a byte stream.
+///
+/// Containers with a different element layout are refused with an `InvalidData` error wrapping a `ContainerError`,
+/// and a truncated container with an `UnexpectedEof` error wrapping a `DetailedError`, see `Error::from_io_error()`.
+/// Zero-sized types are refused with an `InvalidInput` error wrapping an `ErrorReason::ZeroSizedType` `Error`.
+///
+/// Memory is allocated as the element data is read, so a header claiming more elements than follow it can't exhaust it.
+pub fn read<T: PodTransmutable>(reader: &mut impl Read) -> io::Result<Vec<T>> {
+    check_not_zero_sized::<T>(&[])?;
+
+    let mut header = [0u8; HEADER_SIZE];
+    read_pod_into(reader, &mut header)?;
+    let header = Header::parse(&header).map_err(invalid_data)?;
+    header.check::<T>().map_err(invalid_data)?;
+
+    let len = usize::try_from(header.element_count)
+        .ok()
+        .and_then(|count| count.checked_mul(size_of::<T>()))
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "Element count too large"))?;
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < len {
+        let err = Error {
+            required: len,
+            actual: bytes.len(),
+            reason: ErrorReason::NotEnoughBytes,
+        };
+        let index = bytes.len() / size_of::<T>();
+        let partial = index * size_of::<T>();
+        return Err(io::Error::new(ErrorKind::UnexpectedEof,
+                                  DetailedError::new::<T>(err, &bytes[partial..]).at_offset(partial).at_index(index)));
+    }
+    guarded_transmute_pod_vec_pedantic(bytes).map_err(|err| invalid_data(Error::from(err).into()))
+}
+
+/// View the elements of a container in place.
+///
+/// The element data must be suitably aligned for `T`, which it is if the container is,
+/// and contain exactly as many elements as the header specifies.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many, zeroed_vec, container};
+/// // Write into a buffer of u64s, so it's suitably aligned
+/// let mut buf = zeroed_vec::<u64>(container::HEADER_SIZE / 8 + 3);
+/// container::write(&mut guarded_transmute_to_bytes_pod_many_mut(&mut buf), &[1u64, 2, 3]).unwrap();
+///
+/// let (header, values) = container::view::<u64>(guarded_transmute_to_bytes_pod_many(&buf)).unwrap();
+/// assert_eq!(header.element_count, 3);
+/// assert_eq!(values, &[1, 2, 3]);
+/// ```
+pub fn view<T: PodTransmutable>(bytes: &[u8]) -> Result<(Header, &[T]), ContainerError> {
+    let header = Header::parse(bytes)?;
+    header.check::<T>()?;
+
+    let data = &bytes[HEADER_SIZE..];
+    let values = if header.element_count == 0 && data.is_empty() {
+        &[]
+    } else {
+        guarded_transmute_pod_many_pedantic::<T>(data)?
+    };
+
+    if values.len() as u64 != header.element_count {
+        return Err(ContainerError::CountMismatch {
+            expected: header.element_count,
+            found: values.len() as u64,
+        });
+    }
+    Ok((header, values))
+}
+
+
+fn invalid_data(err: ContainerError) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, err)
+}
+
+
+/// 64-bit FNV-1a, chosen for being trivial and stable.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Fnv1a {
+        Fnv1a(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write(&(value as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_usize(value.len());
+        self.write(value.as_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "std")]
+use self::super::{LayoutKind, TypeLayout};
+
+
+/// Byte order of multi-byte values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl Endianness {
+    /// The target platform's byte order.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endianness = Endianness::Little;
+    /// The target platform's byte order.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endianness = Endianness::Big;
+
+    /// Check whether this is the target platform's byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::Endianness;
+    /// assert!(Endianness::NATIVE.is_native());
+    /// assert_eq!(Endianness::Little.is_native(), cfg!(target_endian = "little"));
+    /// ```
+    pub fn is_native(self) -> bool {
+        self == Endianness::NATIVE
+    }
+}
+
+
+/// Reverse the bytes of each `size`-byte element in `bytes`, converting them between byte orders.
+#[cfg(feature = "std")]
+pub(crate) fn swap_elements(bytes: &mut [u8], size: usize) {
+    if size > 1 {
+        for elem in bytes.chunks_mut(size) {
+            elem.reverse();
+        }
+    }
+}
+
+/// Collect the offsets and sizes of the multi-byte primitives making up a type into `lanes`,
+/// returning `false` if part of it is `Opaque`, so its byte order can't be converted.
+#[cfg(feature = "std")]
+pub(crate) fn primitive_lanes(layout: &TypeLayout, offset: usize, lanes: &mut Vec<(usize, usize)>) -> bool {
+    match layout.kind {
+        LayoutKind::Primitive => {
+            if layout.size > 1 {
+                lanes.push((offset, layout.size));
+            }
+            true
+        }
+        LayoutKind::Array { ref element, len } => (0..len).all(|i| primitive_lanes(element, offset + i * element.size, lanes)),
+        LayoutKind::Struct { ref fields } => fields.iter().all(|field| primitive_lanes(&field.layout, offset + field.offset, lanes)),
+        LayoutKind::Opaque => layout.size <= 1,
+    }
+}
+
+/// Reverse the bytes of each lane in each `size`-byte element in `bytes`, converting them between byte orders.
+#[cfg(feature = "std")]
+pub(crate) fn swap_lanes(bytes: &mut [u8], size: usize, lanes: &[(usize, usize)]) {
+    for elem in bytes.chunks_mut(size) {
+        for &(offset, len) in lanes {
+            elem[offset..offset + len].reverse();
+        }
+    }
+}
@@ -1,5 +1,14 @@
+#[cfg(feature = "std")]
 use std::error::Error as StdError;
-use std::fmt;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use self::super::PodTransmutable;
+use core::any::type_name;
+use core::ops::Deref;
+use core::mem::size_of;
+use core::{cmp, fmt};
 
 
 /// A transmutation error.
@@ -28,31 +37,389 @@ pub struct Error {
 }
 
 /// How the type's size compares to the received byte count and the transmutation function's characteristic.
+///
+/// More reasons may be added in the future, so matches on it need a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ErrorReason {
     /// Too few bytes to fill even one instance of a type.
     NotEnoughBytes,
-    /// Too many bytes to fill a type.
+    /// More bytes than allowed by an element count limit.
     ///
-    /// Currently unused.
+    /// `required` is then the byte count of the maximal or exact amount of elements.
     TooManyBytes,
     /// The byte amount received is not the same as the type's size.
     InexactByteCount,
+    /// The bytes don't start at an address suitably aligned for the type.
+    ///
+    /// `required` is then the type's alignment, and `actual` the start address' offset from the preceding aligned address.
+    UnalignedMemory,
+    /// The type is zero-sized, so a byte count doesn't determine an amount of instances.
+    ///
+    /// `required` is then the type's size of `0`.
+    ZeroSizedType,
+    /// The distance between consecutive records or rows is too short to fit an element or row.
+    ///
+    /// `required` is then the least distance that fits, or `usize::MAX` if that overflows, and `actual` the distance.
+    InvalidStride,
 }
 
 
-impl StdError for Error {
-    fn description(&self) -> &str {
-        match self.reason {
-            ErrorReason::NotEnoughBytes => "Not enough bytes to fill type",
-            ErrorReason::TooManyBytes => "Too many bytes for type",
-            ErrorReason::InexactByteCount => "Not exactly the amount of bytes for type",
+/// A transmutation error from a function taking ownership of a byte vector, which hands it back.
+///
+/// Dereferences to the underlying `Error`, and displays as it,
+/// so the `Error` isn't also reported as its `source()`.
+///
+/// Available with the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, guarded_transmute_pod_vec_pedantic};
+/// let err = guarded_transmute_pod_vec_pedantic::<u16>(vec![0x00, 0x01, 0x02]).unwrap_err();
+/// assert_eq!(err.reason, ErrorReason::InexactByteCount);
+///
+/// let mut bytes = err.into_inner();
+/// bytes.push(0x03);
+/// assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(bytes).unwrap().len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VecError {
+    /// Why the transmutation failed.
+    pub error: Error,
+    bytes: Vec<u8>,
+}
+
+
+#[cfg(feature = "alloc")]
+impl VecError {
+    /// Wrap an error with the byte vector that caused it.
+    pub fn new(error: Error, bytes: Vec<u8>) -> VecError {
+        VecError {
+            error,
+            bytes,
         }
     }
+
+    /// Get the original byte vector back.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for VecError {
+    type Target = Error;
+
+    fn deref(&self) -> &Error {
+        &self.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<VecError> for Error {
+    fn from(err: VecError) -> Error {
+        err.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for VecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("VecError")
+            .field("error", &self.error)
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for VecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for VecError {}
+
+
+/// A transmutation error with context for diagnostics:
+/// the target type's name, where in the input it occurred, and a preview of the offending bytes.
+///
+/// Dereferences to the underlying `Error`, and its `Display` output includes all of the context,
+/// so the `Error` isn't also reported as its `source()`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorContext, guarded_transmute_pod};
+/// let packet = [0x01, 0x02, 0x03, 0xAA, 0xBB];
+/// let payload = &packet[3..];
+/// let err = guarded_transmute_pod::<u32>(payload).with_context(payload).unwrap_err().at_offset(3);
+///
+/// assert_eq!(err.type_name, "u32");
+/// assert_eq!(err.preview(), &[0xAA, 0xBB]);
+/// assert_eq!(err.to_string(),
+///            "Not enough bytes to fill type `u32` (required: 4, actual: 2) at byte offset 3: aa bb");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetailedError {
+    /// Why the transmutation failed.
+    pub error: Error,
+    /// The name of the type being transmuted into, as returned by `std::any::type_name()`.
+    pub type_name: &'static str,
+    /// Offset of the offending bytes from the start of the input, if known.
+    pub offset: Option<usize>,
+    /// Index of the offending element, if known.
+    pub index: Option<usize>,
+    preview: [u8; DetailedError::PREVIEW_LEN],
+    preview_len: usize,
+    preview_truncated: bool,
+}
+
+
+impl Error {
+    /// Add the name of `T` and a preview of the start of `bytes` to this error.
+    ///
+    /// `ErrorContext::with_context()` infers `T` from the result of a guard instead, and previews the offending bytes.
+    pub fn with_context<T>(self, bytes: &[u8]) -> DetailedError {
+        DetailedError::new::<T>(self, bytes)
+    }
+
+    /// Get the transmutation error an `io::Error` wraps, either directly, or in a `DetailedError` or `VecError`.
+    ///
+    /// The stream-reading functions, like `read_pod()`, wrap a `DetailedError`,
+    /// so this is the way to get at the underlying `Error` regardless of how much context it carries.
+    ///
+    /// Available with the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{ErrorReason, Error, read_pod};
+    /// let err = read_pod::<u32>(&mut &[0x00][..]).unwrap_err();
+    /// assert_eq!(Error::from_io_error(&err),
+    ///            Some(Error {
+    ///                required: 32 / 8,
+    ///                actual: 1,
+    ///                reason: ErrorReason::NotEnoughBytes,
+    ///            }));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_io_error(err: &io::Error) -> Option<Error> {
+        let inner = err.get_ref()?;
+        if let Some(err) = inner.downcast_ref::<Error>() {
+            Some(*err)
+        } else if let Some(err) = inner.downcast_ref::<DetailedError>() {
+            Some(err.error)
+        } else {
+            inner.downcast_ref::<VecError>().map(|err| err.error)
+        }
+    }
+}
+
+
+/// Attach context to the error of a guarded transmutation, naming the type it was transmuting into.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorContext, guarded_transmute_pod_many_pedantic};
+/// let bytes = [0x01, 0x02, 0x03];
+/// let err = guarded_transmute_pod_many_pedantic::<u16>(&bytes).with_context(&bytes).unwrap_err();
+/// assert_eq!(err.type_name, "u16");
+/// assert_eq!((err.offset, err.index), (Some(2), Some(1)));
+/// assert_eq!(err.preview(), &[0x03]);
+/// ```
+pub trait ErrorContext {
+    /// The successfully transmuted value.
+    type Output;
+
+    /// Wrap the error, if any, in a `DetailedError` with a preview of the offending bytes in `bytes`, the input to the transmutation.
+    ///
+    /// If those are past the start of the input, like the extraneous bytes refused by a pedantic guard,
+    /// the preview starts at them, and their offset (and, for slices, element index) is recorded.
+    fn with_context(self, bytes: &[u8]) -> Result<Self::Output, DetailedError>;
+}
+
+impl<T: PodTransmutable> ErrorContext for Result<T, Error> {
+    type Output = T;
+
+    fn with_context(self, bytes: &[u8]) -> Result<T, DetailedError> {
+        self.map_err(|err| {
+            // Only a pedantic guard's extraneous bytes come after the start
+            let offset = match err.reason {
+                ErrorReason::InexactByteCount if err.actual > err.required => err.required,
+                _ => 0,
+            };
+            locate::<T>(err, bytes, offset, None)
+        })
+    }
+}
+
+impl<'a, T: PodTransmutable> ErrorContext for Result<&'a [T], Error> {
+    type Output = &'a [T];
+
+    fn with_context(self, bytes: &[u8]) -> Result<&'a [T], DetailedError> {
+        self.map_err(|err| {
+            // Past the last whole element for pedantic guards, and the last allowed one for count limited ones
+            let offset = match err.reason {
+                ErrorReason::InexactByteCount if err.required != 0 => err.actual - err.actual % err.required,
+                ErrorReason::TooManyBytes => err.required,
+                _ => 0,
+            };
+            let index = offset.checked_div(size_of::<T>());
+            locate::<T>(err, bytes, offset, index)
+        })
+    }
+}
+
+/// Wrap an error from transmuting `bytes` into `T`, previewing from `offset` and recording it and `index` if past the start.
+fn locate<T>(error: Error, bytes: &[u8], offset: usize, index: Option<usize>) -> DetailedError {
+    let offset = cmp::min(offset, bytes.len());
+    let err = DetailedError::new::<T>(error, &bytes[offset..]);
+    match index {
+        _ if offset == 0 => err,
+        Some(index) => err.at_offset(offset).at_index(index),
+        None => err.at_offset(offset),
+    }
+}
+
+impl DetailedError {
+    /// The maximal amount of offending bytes kept for the preview.
+    pub const PREVIEW_LEN: usize = 16;
+
+    /// Wrap an error from transmuting `bytes` into `T`.
+    pub fn new<T>(error: Error, bytes: &[u8]) -> DetailedError {
+        let preview_len = cmp::min(bytes.len(), DetailedError::PREVIEW_LEN);
+        let mut preview = [0; DetailedError::PREVIEW_LEN];
+        preview[..preview_len].copy_from_slice(&bytes[..preview_len]);
+
+        DetailedError {
+            error,
+            type_name: type_name::<T>(),
+            offset: None,
+            index: None,
+            preview,
+            preview_len,
+            preview_truncated: bytes.len() > preview_len,
+        }
+    }
+
+    /// Record the offset of the offending bytes from the start of the input.
+    pub fn at_offset(self, offset: usize) -> DetailedError {
+        DetailedError { offset: Some(offset), ..self }
+    }
+
+    /// Record the index of the offending element.
+    pub fn at_index(self, index: usize) -> DetailedError {
+        DetailedError { index: Some(index), ..self }
+    }
+
+    /// Get the first `PREVIEW_LEN` offending bytes.
+    pub fn preview(&self) -> &[u8] {
+        &self.preview[..self.preview_len]
+    }
+}
+
+impl Deref for DetailedError {
+    type Target = Error;
+
+    fn deref(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl From<DetailedError> for Error {
+    fn from(err: DetailedError) -> Error {
+        err.error
+    }
+}
+
+impl fmt::Display for DetailedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "{} `{}` (required: {}, actual: {})",
+               self.error.reason,
+               self.type_name,
+               self.error.required,
+               self.error.actual)?;
+        if let Some(offset) = self.offset {
+            write!(f, " at byte offset {}", offset)?;
+        }
+        if let Some(index) = self.index {
+            write!(f, "{} element {}", if self.offset.is_some() { "," } else { " at" }, index)?;
+        }
+        if self.preview_len != 0 {
+            f.write_str(":")?;
+            for b in self.preview() {
+                write!(f, " {:02x}", b)?;
+            }
+            if self.preview_truncated {
+                f.write_str(" ...")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for DetailedError {}
+
+/// Converts into an `io::Error` of the same kind as the underlying `Error` would, wrapping the `DetailedError`.
+///
+/// Available with the `std` feature.
+#[cfg(feature = "std")]
+impl From<DetailedError> for io::Error {
+    fn from(err: DetailedError) -> io::Error {
+        io::Error::new(err.error.reason.io_error_kind(), err)
+    }
+}
+
+
+#[cfg(feature = "std")]
+impl StdError for Error {}
+
+/// Converts into an `io::Error` of kind `UnexpectedEof` for `NotEnoughBytes`, `InvalidInput` for `ZeroSizedType`, and `InvalidData` otherwise,
+/// wrapping the `Error`.
+///
+/// Available with the `std` feature.
+#[cfg(feature = "std")]
+impl From<Error> for io::Error {
+    fn from(err: Error) -> io::Error {
+        io::Error::new(err.reason.io_error_kind(), err)
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} (required: {}, actual: {})", self.description(), self.required, self.actual)
+        write!(f, "{} (required: {}, actual: {})", self.reason, self.required, self.actual)
+    }
+}
+
+
+impl ErrorReason {
+    #[cfg(feature = "std")]
+    fn io_error_kind(self) -> io::ErrorKind {
+        match self {
+            ErrorReason::NotEnoughBytes => io::ErrorKind::UnexpectedEof,
+            ErrorReason::ZeroSizedType => io::ErrorKind::InvalidInput,
+            _ => io::ErrorKind::InvalidData,
+        }
+    }
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ErrorReason::NotEnoughBytes => "Not enough bytes to fill type",
+            ErrorReason::TooManyBytes => "Too many bytes for type",
+            ErrorReason::InexactByteCount => "Not exactly the amount of bytes for type",
+            ErrorReason::UnalignedMemory => "Bytes not aligned for type",
+            ErrorReason::ZeroSizedType => "Type is zero-sized",
+            ErrorReason::InvalidStride => "Stride too short for type",
+        })
     }
 }
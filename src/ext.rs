@@ -0,0 +1,141 @@
+#[cfg(feature = "alloc")]
+use self::super::{VecError, guarded_transmute_pod_vec_pedantic};
+use self::super::{PodTransmutable, Error, guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_many_pedantic,
+                  guarded_transmute_pod_pedantic};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// Method-style access to `guarded_transmute_pod_many_pedantic()` for byte containers.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, PodSliceExt, guarded_transmute_to_bytes_pod_many};
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2]);
+/// assert_eq!(bytes.as_pod_slice::<u16>(), Ok(&[1, 2][..]));
+/// assert_eq!(bytes[1..3].as_pod_slice::<u16>().unwrap_err().reason, ErrorReason::UnalignedMemory);
+/// ```
+pub trait PodSliceExt {
+    /// View these bytes as a slice of POD, with the same semantics as `guarded_transmute_pod_many_pedantic()`.
+    fn as_pod_slice<T: PodTransmutable>(&self) -> Result<&[T], Error>;
+}
+
+impl PodSliceExt for [u8] {
+    fn as_pod_slice<T: PodTransmutable>(&self) -> Result<&[T], Error> {
+        guarded_transmute_pod_many_pedantic(self)
+    }
+}
+
+impl<const N: usize> PodSliceExt for [u8; N] {
+    fn as_pod_slice<T: PodTransmutable>(&self) -> Result<&[T], Error> {
+        guarded_transmute_pod_many_pedantic(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PodSliceExt for Vec<u8> {
+    fn as_pod_slice<T: PodTransmutable>(&self) -> Result<&[T], Error> {
+        guarded_transmute_pod_many_pedantic(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PodSliceExt for Box<[u8]> {
+    fn as_pod_slice<T: PodTransmutable>(&self) -> Result<&[T], Error> {
+        guarded_transmute_pod_many_pedantic(self)
+    }
+}
+
+
+/// Method-style access to `guarded_transmute_pod_vec_pedantic()` for owned byte containers.
+///
+/// Available with the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::IntoPodVec;
+/// assert_eq!(vec![0x00u8; 8].into_pod_vec::<u32>().unwrap(), vec![0, 0]);
+/// assert_eq!(vec![0x00u8; 7].into_pod_vec::<u32>().unwrap_err().into_inner(), vec![0x00; 7]);
+/// ```
+#[cfg(feature = "alloc")]
+pub trait IntoPodVec {
+    /// Transform these bytes into a vector of POD, with the same semantics as `guarded_transmute_pod_vec_pedantic()`.
+    fn into_pod_vec<T: PodTransmutable>(self) -> Result<Vec<T>, VecError>;
+}
+
+#[cfg(feature = "alloc")]
+impl IntoPodVec for Vec<u8> {
+    fn into_pod_vec<T: PodTransmutable>(self) -> Result<Vec<T>, VecError> {
+        guarded_transmute_pod_vec_pedantic(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl IntoPodVec for Box<[u8]> {
+    fn into_pod_vec<T: PodTransmutable>(self) -> Result<Vec<T>, VecError> {
+        guarded_transmute_pod_vec_pedantic(self.into_vec())
+    }
+}
+
+/// Copies the bytes into a new vector first.
+#[cfg(feature = "alloc")]
+impl<const N: usize> IntoPodVec for [u8; N] {
+    fn into_pod_vec<T: PodTransmutable>(self) -> Result<Vec<T>, VecError> {
+        guarded_transmute_pod_vec_pedantic(self.to_vec())
+    }
+}
+
+
+/// Method-style access to `guarded_transmute_to_bytes_pod_many{,_mut}()` for POD slices.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::PodAsBytes;
+/// let mut values = [0u16; 2];
+/// values.as_bytes_mut()[2..].copy_from_slice(&7u16.to_ne_bytes());
+/// assert_eq!(values, [0, 7]);
+/// assert_eq!(values.as_bytes().len(), 4);
+/// ```
+pub trait PodAsBytes {
+    /// View these values as bytes.
+    fn as_bytes(&self) -> &[u8];
+
+    /// View these values as mutable bytes.
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+}
+
+impl<T: PodTransmutable> PodAsBytes for [T] {
+    fn as_bytes(&self) -> &[u8] {
+        guarded_transmute_to_bytes_pod_many(self)
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        guarded_transmute_to_bytes_pod_many_mut(self)
+    }
+}
+
+
+/// Constructor-style access to `guarded_transmute_pod_pedantic()`, for all `Copy`able POD.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::PodFromBytes;
+/// assert_eq!(u32::from_bytes(&0x01020304u32.to_ne_bytes()), Ok(0x01020304));
+/// assert!(u32::from_bytes(&[0x00; 5]).is_err());
+/// ```
+pub trait PodFromBytes: Sized {
+    /// Read a value from bytes, with the same semantics as `guarded_transmute_pod_pedantic()`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+impl<T: PodTransmutable + Copy> PodFromBytes for T {
+    fn from_bytes(bytes: &[u8]) -> Result<T, Error> {
+        guarded_transmute_pod_pedantic(bytes)
+    }
+}
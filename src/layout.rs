@@ -0,0 +1,348 @@
+#[cfg(feature = "alloc")]
+use core::mem::align_of;
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use core::any::type_name;
+use self::super::PodTransmutable;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// Runtime description of a `PodTransmutable` type's memory layout.
+///
+/// Available with the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{LayoutKind, TypeLayout, PodTransmutable};
+/// let layout = <[u16; 3]>::layout();
+/// assert_eq!(layout.size, 6);
+/// assert_eq!(layout.align, 2);
+/// assert_eq!(layout.kind,
+///            LayoutKind::Array {
+///                element: Box::new(u16::layout()),
+///                len: 3,
+///            });
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypeLayout {
+    /// The type's name, as returned by `std::any::type_name()`.
+    pub name: &'static str,
+    /// The type's size in bytes.
+    pub size: usize,
+    /// The type's alignment in bytes.
+    pub align: usize,
+    /// The type's structure.
+    pub kind: LayoutKind,
+}
+
+/// The structure of a type described by a `TypeLayout`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LayoutKind {
+    /// A primitive type, like `u32` or `f64`.
+    Primitive,
+    /// A fixed-size array.
+    Array {
+        /// The layout of a single element.
+        element: Box<TypeLayout>,
+        /// The amount of elements.
+        len: usize,
+    },
+    /// A struct with the specified fields, in declaration order.
+    Struct {
+        /// The struct's fields.
+        fields: Vec<FieldLayout>,
+    },
+    /// A type which doesn't describe its structure.
+    Opaque,
+}
+
+/// Layout of a single field of a struct.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldLayout {
+    /// The field's name.
+    pub name: &'static str,
+    /// Offset of the field from the start of the struct in bytes.
+    pub offset: usize,
+    /// The field type's layout.
+    pub layout: TypeLayout,
+}
+
+#[cfg(feature = "alloc")]
+impl TypeLayout {
+    /// Get a layout with the size and alignment of `T`, and the specified structure.
+    pub fn new<T>(kind: LayoutKind) -> TypeLayout {
+        TypeLayout {
+            name: type_name::<T>(),
+            size: size_of::<T>(),
+            align: align_of::<T>(),
+            kind,
+        }
+    }
+
+    /// Get the layout of a struct `T` with the specified fields.
+    pub fn of_struct<T>(fields: Vec<FieldLayout>) -> TypeLayout {
+        TypeLayout::new::<T>(LayoutKind::Struct { fields })
+    }
+
+    /// Get the layout of a field of type `F` at `offset` in `S`.
+    ///
+    /// `field`, which maps a pointer to the struct to a pointer to the field, is never called,
+    /// and only serves to infer `F` in `pod_transmutable!()`.
+    #[doc(hidden)]
+    pub fn field<S, F: PodTransmutable>(name: &'static str, offset: usize, _field: fn(*const S) -> *const F) -> FieldLayout {
+        FieldLayout {
+            name,
+            offset,
+            layout: F::layout(),
+        }
+    }
+}
+
+
+/// Get the size of a field of type `F` in `S`, checking that it's `PodTransmutable`.
+///
+/// `field` is never called, and only serves to infer `F` in `pod_transmutable!()`.
+#[doc(hidden)]
+pub const fn __pod_field_size<S, F: PodTransmutable>(_field: fn(*const S) -> *const F) -> usize {
+    size_of::<F>()
+}
+
+
+/// Implement `PodTransmutable` for a struct, describing the specified fields in its `layout()`.
+///
+/// All fields must be `PodTransmutable` themselves, and listed in declaration order.
+/// The struct should be `#[repr(C)]` (or `#[repr(transparent)]`), so its layout is stable.
+///
+/// The build fails if a field isn't `PodTransmutable`, a field is missing from the list, or the struct has padding,
+/// since any of these would make viewing it as bytes, or bytes as it, unsound.
+///
+/// # Packed structs
+///
+/// `#[repr(C, packed)]` structs have an alignment of 1, so they can be viewed at any byte offset,
+/// but taking a reference to one of their fields is an error, since it may be unaligned.
+/// Prefixing the struct with `packed` and listing the fields with their types (and optional visibility)
+/// additionally generates a by-value getter for each field, named after it, which is safe to use regardless of alignment.
+/// (Assigning to a packed field is already safe.)
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// # #[cfg(feature = "alloc")]
+/// # use safe_transmute::{LayoutKind, PodTransmutable};
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+///     flags: [u8; 2],
+/// }
+///
+/// pod_transmutable!(Header { magic, len, flags });
+///
+/// # fn main() {
+/// # #[cfg(feature = "alloc")] {
+/// let layout = Header::layout();
+/// assert_eq!(layout.size, 8);
+/// match layout.kind {
+///     LayoutKind::Struct { fields } => {
+///         assert_eq!(fields.iter().map(|f| (f.name, f.offset)).collect::<Vec<_>>(),
+///                    vec![("magic", 0), ("len", 4), ("flags", 6)]);
+///         assert_eq!(fields[1].layout, u16::layout());
+///     }
+///     _ => unreachable!(),
+/// }
+/// # }
+/// # }
+/// ```
+///
+/// A packed struct viewed at an odd offset:
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// # use safe_transmute::guarded_transmute_pod_many;
+/// #[derive(Clone, Copy)]
+/// #[repr(C, packed)]
+/// struct Record {
+///     tag: u8,
+///     value: u32,
+/// }
+///
+/// pod_transmutable!(packed Record { pub tag: u8, pub value: u32 });
+///
+/// # fn main() {
+/// let bytes = [[0xFF, 0x01].to_vec(), 0x02030405u32.to_ne_bytes().to_vec()].concat();
+/// let records = guarded_transmute_pod_many::<Record>(&bytes[1..]).unwrap();
+/// assert_eq!(records[0].tag(), 0x01);
+/// assert_eq!(records[0].value(), 0x02030405);
+/// # }
+/// ```
+///
+/// Padding is rejected, since it would be read as uninitialised bytes:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// #[repr(C)]
+/// struct Padded {
+///     a: u8,
+///     b: u32,
+/// }
+///
+/// pod_transmutable!(Padded { a, b });
+/// # fn main() {}
+/// ```
+///
+/// As are unlisted fields, which could be anything:
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// #[repr(C)]
+/// struct Borrowing {
+///     a: u64,
+///     b: &'static u8,
+/// }
+///
+/// pod_transmutable!(Borrowing { a });
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! pod_transmutable {
+    (packed $ty:ident { $($vis:vis $field:ident : $fty:ty),* $(,)* }) => {
+        $crate::pod_transmutable!($ty { $($field),* });
+
+        impl $ty {
+            $(
+                #[allow(dead_code)]
+                $vis fn $field(&self) -> $fty {
+                    self.$field
+                }
+            )*
+        }
+    };
+
+    ($ty:path { $($field:ident),* $(,)* }) => {
+        unsafe impl $crate::PodTransmutable for $ty {
+            $crate::__pod_transmutable_layout!($ty { $($field),* });
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn all_fields_listed(s: &$ty) {
+                let $ty { $($field: _),* } = *s;
+            }
+
+            assert!(0 $(+ $crate::__pod_field_size(|s: *const $ty| unsafe { $crate::__ptr::addr_of!((*s).$field) }))* ==
+                    $crate::__mem::size_of::<$ty>(),
+                    concat!("`", stringify!($ty), "` has padding"));
+        };
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pod_transmutable_layout {
+    ($ty:ty { $($field:ident),* }) => {
+        fn layout() -> $crate::TypeLayout {
+            $crate::TypeLayout::of_struct::<$ty>([$($crate::TypeLayout::field(stringify!($field),
+                                                                           $crate::__mem::offset_of!($ty, $field),
+                                                                           |s: *const $ty| unsafe { $crate::__ptr::addr_of!((*s).$field) })),*]
+                .into())
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pod_transmutable_layout {
+    ($ty:ty { $($field:ident),* }) => {};
+}
+
+/// Assert at compile time that a `PodTransmutable` type has the specified size, alignment and field offsets.
+///
+/// The alignment and field offsets are optional, and only the listed fields are checked.
+/// Any mismatch, or the type not being `PodTransmutable`, fails the build.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+///     offsets: [u64; 2],
+/// }
+///
+/// pod_transmutable!(Header { magic, len, offsets });
+/// assert_pod_layout!(Header, size = 24, align = 8, fields { magic: 0, len: 4, offsets: 8 });
+/// # fn main() {}
+/// ```
+///
+/// Layout drift is caught by the compiler:
+///
+/// ```compile_fail,E0080
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// #[repr(C)]
+/// struct Header {
+///     magic: u16,
+///     version: u16,
+///     len: u32,
+/// }
+///
+/// pod_transmutable!(Header { magic, version, len });
+/// assert_pod_layout!(Header, size = 8, fields { magic: 0, len: 2 });
+/// # fn main() {}
+/// ```
+///
+/// As are a wrong size or alignment:
+///
+/// ```compile_fail,E0080
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// assert_pod_layout!([u16; 3], size = 6, align = 4);
+/// # fn main() {}
+/// ```
+///
+/// And types that aren't `PodTransmutable`:
+///
+/// ```compile_fail,E0277
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// assert_pod_layout!(bool, size = 1);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_pod_layout {
+    ($ty:ty, size = $size:expr $(, align = $align:expr)? $(, fields { $($field:ident : $offset:expr),* $(,)? })? $(,)?) => {
+        const _: () = {
+            const fn assert_pod_transmutable<T: $crate::PodTransmutable>() {}
+            assert_pod_transmutable::<$ty>();
+
+            assert!($crate::__mem::size_of::<$ty>() == $size,
+                    concat!("size of `", stringify!($ty), "` is not ", stringify!($size)));
+            $(
+                assert!($crate::__mem::align_of::<$ty>() == $align,
+                        concat!("alignment of `", stringify!($ty), "` is not ", stringify!($align)));
+            )?
+            $($(
+                assert!($crate::__mem::offset_of!($ty, $field) == $offset,
+                        concat!("offset of `", stringify!($ty), "::", stringify!($field), "` is not ", stringify!($offset)));
+            )*)?
+        };
+    };
+}
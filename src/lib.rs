@@ -8,6 +8,12 @@
 //! but they don't do that (see [here](https://github.com/nabijaczleweli/safe-transmute-rs/issues/1)
 //! for extended discussion).
 //!
+//! # Features
+//!
+//! The crate is `#![no_std]` without the `std` feature, which is enabled by default.
+//! The `Vec`- and `Box`-based functions and `TypeLayout` additionally need the `alloc` feature (implied by `std`),
+//! and the I/O-based readers, writers and file formats need `std`.
+//!
 //! # Examples
 //!
 //! View bytes as a series of `u16`s:
@@ -64,18 +70,98 @@
 //! ```
 
 
+#![cfg_attr(not(feature = "std"), no_std)]
+// `usize::is_multiple_of()` is only available since Rust 1.87
+#![allow(clippy::manual_is_multiple_of)]
+
+
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(feature = "ndarray")]
+extern crate ndarray as ndarray_crate;
+#[cfg(feature = "bytes")]
+extern crate bytes as bytes_crate;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck as bytemuck_crate;
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy as zerocopy_crate;
+
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+#[macro_use]
+mod bridge;
 mod pod;
 mod error;
+mod zeroable;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "std")]
+mod writer;
+mod endian;
+mod layout;
+mod strided;
+mod shaped;
+mod pod_iter;
+mod ext;
 
-use std::slice;
-use std::mem::{align_of, forget};
+use core::{ptr, slice};
+use core::ptr::NonNull;
+use core::mem::{align_of, size_of};
+#[doc(hidden)]
+pub use core::{mem as __mem, ptr as __ptr};
+#[cfg(feature = "alloc")]
+use core::mem::forget;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 pub mod util;
+#[cfg(feature = "std")]
+pub mod npy;
+#[cfg(feature = "std")]
+pub mod container;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+#[cfg(feature = "zerocopy")]
+pub mod zerocopy;
 
-pub use self::error::{ErrorReason, Error};
-pub use self::pod::{PodTransmutable, guarded_transmute_pod_many_permissive, guarded_transmute_pod_vec_permissive, guarded_transmute_pod_many_pedantic,
-                    guarded_transmute_pod_pedantic, guarded_transmute_pod_vec_pedantic, guarded_transmute_pod_many, guarded_transmute_pod,
-                    guarded_transmute_pod_vec};
+pub use self::error::{DetailedError, ErrorContext, ErrorReason, Error};
+#[cfg(feature = "alloc")]
+pub use self::error::VecError;
+pub use self::zeroable::{Zeroable, zeroed};
+#[cfg(feature = "alloc")]
+pub use self::zeroable::{zeroed_vec, zeroed_box};
+#[cfg(feature = "std")]
+pub use self::reader::{PartialRecord, PodReader, read_pod_into, read_pod};
+#[cfg(feature = "std")]
+pub use self::writer::PodWriter;
+pub use self::endian::Endianness;
+#[cfg(feature = "alloc")]
+pub use self::layout::{FieldLayout, LayoutKind, TypeLayout};
+#[doc(hidden)]
+pub use self::layout::__pod_field_size;
+pub use self::strided::{StridedSlice, StridedIter};
+pub use self::shaped::{PodArrayView, PodMatrix, PodRows};
+pub use self::pod_iter::{PodIter, pod_iter_permissive, pod_iter_pedantic, pod_iter};
+pub use self::ext::{PodSliceExt, PodFromBytes, PodAsBytes};
+#[cfg(feature = "alloc")]
+pub use self::ext::IntoPodVec;
+pub use self::pod::{PodTransmutable, guarded_transmute_pod_many_permissive, guarded_transmute_pod_many_pedantic, guarded_transmute_pod_many_exact,
+                    guarded_transmute_pod_many_max, guarded_transmute_pod_pedantic, guarded_transmute_pod_many, guarded_transmute_pod,
+                    guarded_transmute_pod_array_owned_pedantic, guarded_transmute_pod_array_mut_pedantic, guarded_transmute_pod_array_pedantic,
+                    guarded_transmute_pod_array_owned, guarded_transmute_pod_array_mut, guarded_transmute_pod_array,
+                    guarded_transmute_pod_ref_mut_pedantic, guarded_transmute_pod_ref_pedantic, guarded_transmute_pod_ref_mut, guarded_transmute_pod_ref,
+                    guarded_transmute_to_bytes_pod_many, guarded_transmute_to_bytes_pod_many_mut, pod_align_to_mut, pod_align_to};
+#[cfg(feature = "alloc")]
+pub use self::pod::{guarded_transmute_pod_vec_permissive, guarded_transmute_pod_vec_pedantic, guarded_transmute_pod_vec};
 
 
 /// Transmute a byte slice into a single instance of a `Copy`able type.
@@ -83,6 +169,10 @@ pub use self::pod::{PodTransmutable, guarded_transmute_pod_many_permissive, guar
 /// The byte slice must have at least enough bytes to fill a single instance of a type,
 /// extraneous data is ignored.
 ///
+/// # Safety
+///
+/// The bytes must form a valid bit pattern for `T`.
+///
 /// # Examples
 ///
 /// ```
@@ -99,14 +189,14 @@ pub use self::pod::{PodTransmutable, guarded_transmute_pod_many_permissive, guar
 /// # }
 /// ```
 pub unsafe fn guarded_transmute<T: Copy>(bytes: &[u8]) -> Result<T, Error> {
-    if bytes.len() < align_of::<T>() {
+    if bytes.len() < size_of::<T>() {
         Err(Error {
-            required: align_of::<T>(),
+            required: size_of::<T>(),
             actual: bytes.len(),
             reason: ErrorReason::NotEnoughBytes,
         })
     } else {
-        Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, 1)[0])
+        Ok(ptr::read_unaligned(bytes.as_ptr() as *const T))
     }
 }
 
@@ -114,6 +204,10 @@ pub unsafe fn guarded_transmute<T: Copy>(bytes: &[u8]) -> Result<T, Error> {
 ///
 /// The byte slice must have exactly enough bytes to fill a single instance of a type.
 ///
+/// # Safety
+///
+/// The bytes must form a valid bit pattern for `T`.
+///
 /// # Examples
 ///
 /// ```
@@ -130,21 +224,26 @@ pub unsafe fn guarded_transmute<T: Copy>(bytes: &[u8]) -> Result<T, Error> {
 /// # }
 /// ```
 pub unsafe fn guarded_transmute_pedantic<T: Copy>(bytes: &[u8]) -> Result<T, Error> {
-    if bytes.len() != align_of::<T>() {
+    if bytes.len() != size_of::<T>() {
         Err(Error {
-            required: align_of::<T>(),
+            required: size_of::<T>(),
             actual: bytes.len(),
             reason: ErrorReason::InexactByteCount,
         })
     } else {
-        Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, 1)[0])
+        Ok(ptr::read_unaligned(bytes.as_ptr() as *const T))
     }
 }
 
 /// View a byte slice as a slice of an arbitrary type.
 ///
 /// The byte slice must have at least enough bytes to fill a single instance of a type,
-/// extraneous data is ignored.
+/// extraneous data is ignored, and must be suitably aligned for `T`.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
 ///
 /// # Examples
 ///
@@ -163,20 +262,27 @@ pub unsafe fn guarded_transmute_pedantic<T: Copy>(bytes: &[u8]) -> Result<T, Err
 /// # }
 /// ```
 pub unsafe fn guarded_transmute_many<T>(bytes: &[u8]) -> Result<&[T], Error> {
-    if bytes.len() < align_of::<T>() {
+    check_not_zero_sized::<T>(bytes)?;
+    if bytes.len() < size_of::<T>() {
         Err(Error {
-            required: align_of::<T>(),
+            required: size_of::<T>(),
             actual: bytes.len(),
             reason: ErrorReason::NotEnoughBytes,
         })
     } else {
+        check_alignment::<T>(bytes)?;
         Ok(guarded_transmute_many_permissive(bytes))
     }
 }
 
 /// View a byte slice as a slice of an arbitrary type.
 ///
-/// The resulting slice will have as many instances of a type as will fit, rounded down.
+/// The resulting slice will have as many instances of a type as will fit, rounded down,
+/// and will be empty if the byte slice isn't suitably aligned for `T`, or `T` is zero-sized.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
 ///
 /// # Examples
 ///
@@ -187,13 +293,26 @@ pub unsafe fn guarded_transmute_many<T>(bytes: &[u8]) -> Result<&[T], Error> {
 /// # }
 /// ```
 pub unsafe fn guarded_transmute_many_permissive<T>(bytes: &[u8]) -> &[T] {
-    slice::from_raw_parts(bytes.as_ptr() as *const T, (bytes.len() - (bytes.len() % align_of::<T>())) / align_of::<T>())
+    if size_of::<T>() == 0 {
+        return &[];
+    }
+    let len = bytes.len() / size_of::<T>();
+    if len == 0 || check_alignment::<T>(bytes).is_err() {
+        &[]
+    } else {
+        slice::from_raw_parts(bytes.as_ptr() as *const T, len)
+    }
 }
 
 /// View a byte slice as a slice of an arbitrary type.
 ///
 /// The byte slice must have at least enough bytes to fill a single instance of a type,
-/// and should not have extraneous data.
+/// should not have extraneous data, and must be suitably aligned for `T`.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
 ///
 /// # Examples
 ///
@@ -212,20 +331,122 @@ pub unsafe fn guarded_transmute_many_permissive<T>(bytes: &[u8]) -> &[T] {
 /// # }
 /// ```
 pub unsafe fn guarded_transmute_many_pedantic<T>(bytes: &[u8]) -> Result<&[T], Error> {
-    if bytes.len() < align_of::<T>() {
+    check_not_zero_sized::<T>(bytes)?;
+    if bytes.len() < size_of::<T>() {
         Err(Error {
-            required: align_of::<T>(),
+            required: size_of::<T>(),
             actual: bytes.len(),
             reason: ErrorReason::NotEnoughBytes,
         })
-    } else if bytes.len() % align_of::<T>() != 0 {
+    } else if bytes.len() % size_of::<T>() != 0 {
         Err(Error {
-            required: align_of::<T>(),
+            required: size_of::<T>(),
             actual: bytes.len(),
             reason: ErrorReason::InexactByteCount,
         })
     } else {
-        Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / align_of::<T>()))
+        check_alignment::<T>(bytes)?;
+        Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / size_of::<T>()))
+    }
+}
+
+/// View a byte slice as a slice of an arbitrary type, with at most `max` instances.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// and at most enough to fill `max` instances; extraneous data past the last whole instance is ignored.
+/// It must be suitably aligned for `T`, and zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, Error, guarded_transmute_many_max};
+/// # include!("../tests/test_util/le_to_native.rs");
+/// # fn main() {
+/// // Little-endian
+/// # unsafe {
+/// # /*
+/// assert_eq!(guarded_transmute_many_max::<u16>(&[0x00, 0x01, 0x00, 0x02], 2).unwrap(),
+/// # */
+/// # assert_eq!(guarded_transmute_many_max::<u16>(&[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>(), 2).unwrap(),
+///            &[0x0100, 0x0200]);
+/// assert_eq!(guarded_transmute_many_max::<u16>(&[0x00, 0x01, 0x00, 0x02], 1),
+///            Err(Error {
+///                required: 16 / 8,
+///                actual: 4,
+///                reason: ErrorReason::TooManyBytes,
+///            }));
+/// # }
+/// # }
+/// ```
+pub unsafe fn guarded_transmute_many_max<T>(bytes: &[u8], max: usize) -> Result<&[T], Error> {
+    check_not_zero_sized::<T>(bytes)?;
+    if bytes.len() / size_of::<T>() > max {
+        Err(Error {
+            required: max * size_of::<T>(),
+            actual: bytes.len(),
+            reason: ErrorReason::TooManyBytes,
+        })
+    } else {
+        guarded_transmute_many(bytes)
+    }
+}
+
+/// View a byte slice as a slice of exactly `count` instances of an arbitrary type.
+///
+/// The byte slice must have exactly enough bytes to fill `count` instances of a type,
+/// and must be suitably aligned for `T`, unless `count` is `0` or `T` is zero-sized.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, Error, guarded_transmute_many_exact};
+/// # include!("../tests/test_util/le_to_native.rs");
+/// # fn main() {
+/// // Little-endian
+/// # unsafe {
+/// # /*
+/// assert_eq!(guarded_transmute_many_exact::<u16>(&[0x0F, 0x0E, 0x0A, 0x0B], 2).unwrap(),
+/// # */
+/// # assert_eq!(guarded_transmute_many_exact::<u16>(&[0x0F, 0x0E, 0x0A, 0x0B].le_to_native::<u16>(), 2).unwrap(),
+///            &[0x0E0F, 0x0B0A]);
+/// assert_eq!(guarded_transmute_many_exact::<u16>(&[0x0F, 0x0E, 0x0A, 0x0B], 3),
+///            Err(Error {
+///                required: 3 * (16 / 8),
+///                actual: 4,
+///                reason: ErrorReason::NotEnoughBytes,
+///            }));
+/// # }
+/// # }
+/// ```
+pub unsafe fn guarded_transmute_many_exact<T>(bytes: &[u8], count: usize) -> Result<&[T], Error> {
+    let required = count.saturating_mul(size_of::<T>());
+    if bytes.len() < required {
+        Err(Error {
+            required,
+            actual: bytes.len(),
+            reason: ErrorReason::NotEnoughBytes,
+        })
+    } else if bytes.len() > required {
+        Err(Error {
+            required,
+            actual: bytes.len(),
+            reason: ErrorReason::TooManyBytes,
+        })
+    } else if count == 0 {
+        Ok(&[])
+    } else if size_of::<T>() == 0 {
+        Ok(slice::from_raw_parts(NonNull::dangling().as_ptr(), count))
+    } else {
+        check_alignment::<T>(bytes)?;
+        Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, count))
     }
 }
 
@@ -233,7 +454,13 @@ pub unsafe fn guarded_transmute_many_pedantic<T>(bytes: &[u8]) -> Result<&[T], E
 ///
 /// The resulting vec will reuse the allocated byte buffer when possible, and
 /// should have at least enough bytes to fill a single instance of a type.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
 /// Extraneous data is ignored.
+/// On error, the byte vector is handed back in the `VecError`.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
 ///
 /// # Examples
 ///
@@ -258,13 +485,17 @@ pub unsafe fn guarded_transmute_many_pedantic<T>(bytes: &[u8]) -> Result<&[T], E
 /// # }
 /// # }
 /// ```
-pub unsafe fn guarded_transmute_vec<T>(bytes: Vec<u8>) -> Result<Vec<T>, Error> {
-    if bytes.len() < align_of::<T>() {
-        Err(Error {
-            required: align_of::<T>(),
-            actual: bytes.len(),
-            reason: ErrorReason::NotEnoughBytes,
-        })
+#[cfg(feature = "alloc")]
+pub unsafe fn guarded_transmute_vec<T>(bytes: Vec<u8>) -> Result<Vec<T>, VecError> {
+    if let Err(err) = check_not_zero_sized::<T>(&bytes) {
+        Err(VecError::new(err, bytes))
+    } else if bytes.len() < size_of::<T>() {
+        Err(VecError::new(Error {
+                              required: size_of::<T>(),
+                              actual: bytes.len(),
+                              reason: ErrorReason::NotEnoughBytes,
+                          },
+                          bytes))
     } else {
         Ok(guarded_transmute_vec_permissive(bytes))
     }
@@ -273,9 +504,16 @@ pub unsafe fn guarded_transmute_vec<T>(bytes: Vec<u8>) -> Result<Vec<T>, Error>
 /// Trasform a byte vector into a vector of an arbitrary type.
 ///
 /// The vector's allocated byte buffer will be reused when possible, and
-/// have as many instances of a type as will fit, rounded down.
+/// have as many instances of a type as will fit, rounded down, or none if `T` is zero-sized.
 /// Extraneous data is ignored.
 ///
+/// The buffer can only be reused if `T` has an alignment of 1 and the capacity is a multiple of its size,
+/// since it must be freed with the same layout it was allocated with; otherwise the data is copied into a new vector.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
+///
 /// # Examples
 ///
 /// ```
@@ -298,12 +536,26 @@ pub unsafe fn guarded_transmute_vec<T>(bytes: Vec<u8>) -> Result<Vec<T>, Error>
 /// # }
 /// # }
 /// ```
+#[cfg(feature = "alloc")]
 pub unsafe fn guarded_transmute_vec_permissive<T>(mut bytes: Vec<u8>) -> Vec<T> {
-    let ptr = bytes.as_mut_ptr();
-    let capacity = bytes.capacity() / align_of::<T>();
-    let len = bytes.len() / align_of::<T>();
-    forget(bytes);
-    Vec::from_raw_parts(ptr as *mut T, capacity, len)
+    if size_of::<T>() == 0 {
+        return Vec::new();
+    }
+    let len = bytes.len() / size_of::<T>();
+
+    // The allocation is freed with the layout of a `[T; capacity]`, which only matches the one it was made with
+    // if `T` is byte-aligned and the capacity is a whole number of `T`s
+    if align_of::<T>() == 1 && bytes.capacity() % size_of::<T>() == 0 {
+        let ptr = bytes.as_mut_ptr();
+        let capacity = bytes.capacity() / size_of::<T>();
+        forget(bytes);
+        Vec::from_raw_parts(ptr as *mut T, len, capacity)
+    } else {
+        let mut ret = Vec::with_capacity(len);
+        ptr::copy_nonoverlapping(bytes.as_ptr(), ret.as_mut_ptr() as *mut u8, len * size_of::<T>());
+        ret.set_len(len);
+        ret
+    }
 }
 
 
@@ -311,6 +563,12 @@ pub unsafe fn guarded_transmute_vec_permissive<T>(mut bytes: Vec<u8>) -> Vec<T>
 ///
 /// The vector's allocated byte buffer will be reused when possible, and
 /// should not have extraneous data.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+/// On error, the byte vector is handed back in the `VecError`.
+///
+/// # Safety
+///
+/// The bytes must form valid bit patterns for `T`.
 ///
 /// # Examples
 ///
@@ -330,22 +588,56 @@ pub unsafe fn guarded_transmute_vec_permissive<T>(mut bytes: Vec<u8>) -> Vec<T>
 /// # }
 /// # }
 /// ```
-pub unsafe fn guarded_transmute_vec_pedantic<T>(bytes: Vec<u8>) -> Result<Vec<T>, Error> {
-    let a = align_of::<T>();
+#[cfg(feature = "alloc")]
+pub unsafe fn guarded_transmute_vec_pedantic<T>(bytes: Vec<u8>) -> Result<Vec<T>, VecError> {
+    let size = size_of::<T>();
     let len = bytes.len();
-    if len < a {
+    if let Err(err) = check_not_zero_sized::<T>(&bytes) {
+        Err(VecError::new(err, bytes))
+    } else if len < size {
+        Err(VecError::new(Error {
+                              required: size,
+                              actual: len,
+                              reason: ErrorReason::NotEnoughBytes,
+                          },
+                          bytes))
+    } else if len % size != 0 {
+        Err(VecError::new(Error {
+                              required: size,
+                              actual: len,
+                              reason: ErrorReason::InexactByteCount,
+                          },
+                          bytes))
+    } else {
+        Ok(guarded_transmute_vec_permissive(bytes))
+    }
+}
+
+
+/// Check that an arbitrary type isn't zero-sized, so that a byte count determines an amount of its instances.
+pub(crate) fn check_not_zero_sized<T>(bytes: &[u8]) -> Result<(), Error> {
+    if size_of::<T>() == 0 {
         Err(Error {
-            required: a,
-            actual: len,
-            reason: ErrorReason::NotEnoughBytes,
+            required: 0,
+            actual: bytes.len(),
+            reason: ErrorReason::ZeroSizedType,
         })
-    } else if len % a != 0 {
+    } else {
+        Ok(())
+    }
+}
+
+/// Check whether a byte slice is suitably aligned to be viewed as an arbitrary type.
+pub(crate) fn check_alignment<T>(bytes: &[u8]) -> Result<(), Error> {
+    let align = align_of::<T>();
+    let offset = bytes.as_ptr() as usize % align;
+    if offset != 0 {
         Err(Error {
-            required: a,
-            actual: len,
-            reason: ErrorReason::InexactByteCount,
+            required: align,
+            actual: offset,
+            reason: ErrorReason::UnalignedMemory,
         })
     } else {
-        Ok(guarded_transmute_vec_permissive(bytes))
+        Ok(())
     }
 }
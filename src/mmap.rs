@@ -0,0 +1,163 @@
+//! Memory-mapped file views of POD arrays.
+//!
+//! Available with the `mmap` feature.
+
+
+use memmap2::{MmapOptions, MmapMut, Mmap};
+use std::io::{self, ErrorKind};
+use std::ops::{DerefMut, Deref};
+use std::marker::PhantomData;
+use std::fs::File;
+use std::slice;
+use self::super::{PodTransmutable, guarded_transmute_pod_many_pedantic};
+
+
+/// A read-only memory-mapped view of a file as a slice of POD.
+///
+/// The mapped bytes must be suitably aligned for `T` and contain a whole, non-zero amount of `T`s,
+/// as with `guarded_transmute_pod_many_pedantic()`; violations are reported as an `io::Error` of kind `InvalidData`
+/// wrapping an `Error`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # extern crate tempfile;
+/// # use safe_transmute::mmap::MappedPodSlice;
+/// # use std::io::Write;
+/// # fn main() {
+/// let mut file = tempfile::tempfile().unwrap();
+/// file.write_all(&[1u32.to_ne_bytes(), 2u32.to_ne_bytes()].concat()).unwrap();
+///
+/// let values = unsafe { MappedPodSlice::<u32>::map(&file) }.unwrap();
+/// assert_eq!(&values[..], &[1, 2]);
+/// # }
+/// ```
+pub struct MappedPodSlice<T: PodTransmutable> {
+    map: Mmap,
+    len: usize,
+    _type: PhantomData<T>,
+}
+
+/// A read-write memory-mapped view of a file as a slice of POD.
+///
+/// The same restrictions as for `MappedPodSlice` apply; changes are carried through to the file.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # extern crate tempfile;
+/// # use safe_transmute::mmap::MappedPodSliceMut;
+/// # use std::io::{Read, Seek, SeekFrom, Write};
+/// # fn main() {
+/// let mut file = tempfile::tempfile().unwrap();
+/// file.write_all(&[0; 8]).unwrap();
+///
+/// let mut values = unsafe { MappedPodSliceMut::<u16>::map(&file) }.unwrap();
+/// values[1] = 0x0102;
+/// values.flush().unwrap();
+///
+/// let mut bytes = vec![];
+/// file.seek(SeekFrom::Start(2)).unwrap();
+/// file.read_to_end(&mut bytes).unwrap();
+/// assert_eq!(&bytes[..2], &0x0102u16.to_ne_bytes());
+/// # }
+/// ```
+pub struct MappedPodSliceMut<T: PodTransmutable> {
+    map: MmapMut,
+    len: usize,
+    _type: PhantomData<T>,
+}
+
+
+impl<T: PodTransmutable> MappedPodSlice<T> {
+    /// Map the whole of a file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, while it's mapped.
+    pub unsafe fn map(file: &File) -> io::Result<MappedPodSlice<T>> {
+        MappedPodSlice::from_map(Mmap::map(file)?)
+    }
+
+    /// Map `len` bytes of a file, starting at `offset`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, while it's mapped.
+    pub unsafe fn map_window(file: &File, offset: u64, len: usize) -> io::Result<MappedPodSlice<T>> {
+        MappedPodSlice::from_map(MmapOptions::new().offset(offset).len(len).map(file)?)
+    }
+
+    fn from_map(map: Mmap) -> io::Result<MappedPodSlice<T>> {
+        Ok(MappedPodSlice {
+            len: validate::<T>(&map)?,
+            map,
+            _type: PhantomData,
+        })
+    }
+}
+
+impl<T: PodTransmutable> MappedPodSliceMut<T> {
+    /// Map the whole of a file, which must be opened for reading and writing.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, while it's mapped.
+    pub unsafe fn map(file: &File) -> io::Result<MappedPodSliceMut<T>> {
+        MappedPodSliceMut::from_map(MmapMut::map_mut(file)?)
+    }
+
+    /// Map `len` bytes of a file, which must be opened for reading and writing, starting at `offset`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, while it's mapped.
+    pub unsafe fn map_window(file: &File, offset: u64, len: usize) -> io::Result<MappedPodSliceMut<T>> {
+        MappedPodSliceMut::from_map(MmapOptions::new().offset(offset).len(len).map_mut(file)?)
+    }
+
+    /// Flush outstanding changes to the file.
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    fn from_map(map: MmapMut) -> io::Result<MappedPodSliceMut<T>> {
+        Ok(MappedPodSliceMut {
+            len: validate::<T>(&map)?,
+            map,
+            _type: PhantomData,
+        })
+    }
+}
+
+impl<T: PodTransmutable> Deref for MappedPodSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T: PodTransmutable> Deref for MappedPodSliceMut<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T: PodTransmutable> DerefMut for MappedPodSliceMut<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.map.as_mut_ptr() as *mut T, self.len) }
+    }
+}
+
+
+/// Check the mapped bytes' alignment and length, returning the amount of `T`s they contain.
+fn validate<T: PodTransmutable>(bytes: &[u8]) -> io::Result<usize> {
+    guarded_transmute_pod_many_pedantic::<T>(bytes)
+        .map(|values| values.len())
+        .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+}
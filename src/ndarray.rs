@@ -0,0 +1,89 @@
+//! Conversions from bytes to `ndarray` arrays of POD.
+//!
+//! Available with the `ndarray` feature.
+//!
+//! The byte count must match the shape exactly, and viewed bytes must be suitably aligned for `T`, unless there are none.
+//! Zero-sized types are refused with `ErrorReason::ZeroSizedType`, unless the shape is empty.
+
+
+use ndarray_crate::{ArrayView, Dimension, IntoDimension, Array};
+use self::super::{ErrorReason, VecError, Error, PodTransmutable, guarded_transmute_pod_many_pedantic, guarded_transmute_pod_vec_pedantic};
+use core::mem::size_of;
+use alloc::vec::Vec;
+
+
+/// View bytes as an array of POD with the specified shape, in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # extern crate ndarray;
+/// # use safe_transmute::guarded_transmute_to_bytes_pod_many;
+/// # fn main() {
+/// let data = [1u32, 2, 3, 4, 5, 6];
+/// let array = safe_transmute::ndarray::view::<u32, _>(guarded_transmute_to_bytes_pod_many(&data), (2, 3)).unwrap();
+/// assert_eq!(array, ndarray::arr2(&[[1, 2, 3], [4, 5, 6]]));
+/// # }
+/// ```
+pub fn view<'a, T: PodTransmutable, Sh: IntoDimension>(bytes: &'a [u8], shape: Sh) -> Result<ArrayView<'a, T, Sh::Dim>, Error> {
+    let dim = shape.into_dimension();
+    let data = if check_len::<T>(bytes.len(), dim.size())? {
+        &[]
+    } else {
+        guarded_transmute_pod_many_pedantic(bytes)?
+    };
+    Ok(ArrayView::from_shape(dim, data).expect("length checked against shape"))
+}
+
+/// Transform a byte vector into an owned array of POD with the specified shape, in row-major order.
+///
+/// The vector's allocated byte buffer will be reused when possible, as with `guarded_transmute_pod_vec_pedantic()`,
+/// and handed back on error. It needn't be aligned, since it's copied into a new allocation otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # extern crate ndarray;
+/// # use safe_transmute::guarded_transmute_to_bytes_pod_many;
+/// # fn main() {
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[1.0f32, 2.0, 3.0, 4.0]).to_vec();
+/// let array = safe_transmute::ndarray::into_array::<f32, _>(bytes, [2, 2]).unwrap();
+/// assert_eq!(array.column(1), ndarray::arr1(&[2.0, 4.0]));
+/// # }
+/// ```
+pub fn into_array<T: PodTransmutable, Sh: IntoDimension>(bytes: Vec<u8>, shape: Sh) -> Result<Array<T, Sh::Dim>, VecError> {
+    let dim = shape.into_dimension();
+    let data = match check_len::<T>(bytes.len(), dim.size()) {
+        Ok(true) => Vec::new(),
+        Ok(false) => guarded_transmute_pod_vec_pedantic(bytes)?,
+        Err(error) => return Err(VecError::new(error, bytes)),
+    };
+    Ok(Array::from_shape_vec(dim, data).expect("length checked against shape"))
+}
+
+
+/// Check that `len` bytes fill exactly `count` `T`s, returning whether they're empty.
+fn check_len<T>(len: usize, count: usize) -> Result<bool, Error> {
+    let required = count.saturating_mul(size_of::<T>());
+    if size_of::<T>() == 0 && count != 0 {
+        Err(Error {
+            required,
+            actual: len,
+            reason: ErrorReason::ZeroSizedType,
+        })
+    } else if len != required {
+        Err(Error {
+            required,
+            actual: len,
+            reason: if len < required {
+                ErrorReason::NotEnoughBytes
+            } else {
+                ErrorReason::InexactByteCount
+            },
+        })
+    } else {
+        Ok(len == 0)
+    }
+}
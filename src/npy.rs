@@ -0,0 +1,518 @@
+//! Reading and writing NumPy `.npy` arrays.
+//!
+//! An `.npy` file is a short header describing the array's element type and shape, followed by the raw elements.
+//! Arrays of native-endian elements can be viewed in place with `view()`, any supported array can be copied out with `decode()`,
+//! and `write()` produces a file NumPy can `load()`.
+//!
+//! Only simple (non-structured) arrays of the types implementing `NpyType` are supported.
+//!
+//! # Examples
+//!
+//! ```
+//! # use safe_transmute::npy;
+//! let mut file = vec![];
+//! npy::write(&mut file, &[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]).unwrap();
+//!
+//! let (header, values) = npy::decode::<f32>(&file).unwrap();
+//! assert_eq!(header.shape, vec![2, 3]);
+//! assert!(!header.fortran_order);
+//! assert_eq!(values, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+//! ```
+
+
+use std::error::Error as StdError;
+use std::io::{self, ErrorKind, Write};
+use std::mem::size_of;
+use std::fmt;
+use self::super::endian::swap_elements;
+use self::super::{ErrorReason, Endianness, Error, PodTransmutable, guarded_transmute_pod_many_pedantic, guarded_transmute_to_bytes_pod_many,
+                  guarded_transmute_to_bytes_pod_many_mut, zeroed_vec};
+
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+/// The data section is padded to start at a multiple of this, so it's aligned for all supported types.
+const DATA_ALIGNMENT: usize = 64;
+
+
+/// POD type with a NumPy dtype equivalent.
+pub trait NpyType: PodTransmutable {
+    /// The dtype's kind character, e.g. `'u'` for unsigned integers or `'f'` for floats.
+    const KIND: char;
+}
+
+impl NpyType for u8 {
+    const KIND: char = 'u';
+}
+impl NpyType for i8 {
+    const KIND: char = 'i';
+}
+impl NpyType for u16 {
+    const KIND: char = 'u';
+}
+impl NpyType for i16 {
+    const KIND: char = 'i';
+}
+impl NpyType for u32 {
+    const KIND: char = 'u';
+}
+impl NpyType for i32 {
+    const KIND: char = 'i';
+}
+impl NpyType for u64 {
+    const KIND: char = 'u';
+}
+impl NpyType for i64 {
+    const KIND: char = 'i';
+}
+impl NpyType for f32 {
+    const KIND: char = 'f';
+}
+impl NpyType for f64 {
+    const KIND: char = 'f';
+}
+
+
+/// A simple dtype descriptor, like `<u4`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Dtype {
+    /// Byte order of the elements, `None` if not applicable (i.e. for single-byte types).
+    pub endianness: Option<Endianness>,
+    /// The kind character, e.g. `'u'` for unsigned integers or `'f'` for floats.
+    pub kind: char,
+    /// Size of a single element in bytes.
+    pub size: usize,
+}
+
+impl Dtype {
+    /// Get the native-endian dtype of a type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::npy::Dtype;
+    /// # use safe_transmute::Endianness;
+    /// assert_eq!(Dtype::of::<u8>().to_string(), "|u1");
+    /// assert_eq!(Dtype::of::<f64>().endianness, Some(Endianness::NATIVE));
+    /// ```
+    pub fn of<T: NpyType>() -> Dtype {
+        Dtype {
+            endianness: if size_of::<T>() == 1 {
+                None
+            } else {
+                Some(Endianness::NATIVE)
+            },
+            kind: T::KIND,
+            size: size_of::<T>(),
+        }
+    }
+
+    /// Parse a dtype descriptor string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::npy::Dtype;
+    /// # use safe_transmute::Endianness;
+    /// assert_eq!(Dtype::parse(">i2"),
+    ///            Some(Dtype {
+    ///                endianness: Some(Endianness::Big),
+    ///                kind: 'i',
+    ///                size: 2,
+    ///            }));
+    /// assert_eq!(Dtype::parse("[('x', '<f4')]"), None);
+    /// ```
+    pub fn parse(descr: &str) -> Option<Dtype> {
+        let mut chars = descr.chars();
+        let endianness = match chars.next()? {
+            '<' => Some(Endianness::Little),
+            '>' => Some(Endianness::Big),
+            '=' => Some(Endianness::NATIVE),
+            '|' => None,
+            _ => return None,
+        };
+        let kind = chars.next().filter(|c| c.is_ascii_alphabetic())?;
+        let size = chars.as_str().parse().ok()?;
+
+        Some(Dtype {
+            endianness: if size == 1 { None } else { endianness },
+            kind,
+            size,
+        })
+    }
+
+    /// Check whether this describes the same element type as `T`, in any byte order.
+    pub fn matches<T: NpyType>(&self) -> bool {
+        self.kind == T::KIND && self.size == size_of::<T>()
+    }
+}
+
+impl fmt::Display for Dtype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let endianness = match self.endianness {
+            Some(Endianness::Little) => '<',
+            Some(Endianness::Big) => '>',
+            None => '|',
+        };
+        write!(f, "{}{}{}", endianness, self.kind, self.size)
+    }
+}
+
+
+/// A parsed `.npy` header.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Header {
+    /// The format version, as `(major, minor)`.
+    pub version: (u8, u8),
+    /// The array's element type.
+    pub dtype: Dtype,
+    /// Whether the elements are stored in column-major (Fortran) order, rather than row-major (C) order.
+    pub fortran_order: bool,
+    /// The array's dimensions, empty for a scalar.
+    pub shape: Vec<usize>,
+    /// Offset of the first element from the start of the file.
+    pub data_offset: usize,
+}
+
+impl Header {
+    /// Parse the header at the start of a `.npy` file.
+    pub fn parse(bytes: &[u8]) -> Result<Header, NpyError> {
+        if bytes.len() < MAGIC.len() + 2 || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(NpyError::InvalidHeader("missing magic string"));
+        }
+
+        let version = (bytes[MAGIC.len()], bytes[MAGIC.len() + 1]);
+        let len_start = MAGIC.len() + 2;
+        let (dict_start, dict_len) = match version.0 {
+            1 if bytes.len() >= len_start + 2 => (len_start + 2, u16::from_le_bytes([bytes[len_start], bytes[len_start + 1]]) as usize),
+            2 | 3 if bytes.len() >= len_start + 4 => {
+                (len_start + 4, u32::from_le_bytes([bytes[len_start], bytes[len_start + 1], bytes[len_start + 2], bytes[len_start + 3]]) as usize)
+            }
+            1..=3 => return Err(NpyError::InvalidHeader("truncated header")),
+            _ => return Err(NpyError::UnsupportedVersion(version.0, version.1)),
+        };
+
+        let data_offset = dict_start + dict_len;
+        let dict = bytes.get(dict_start..data_offset).ok_or(NpyError::InvalidHeader("truncated header"))?;
+        let dict = std::str::from_utf8(dict).map_err(|_| NpyError::InvalidHeader("header is not valid text"))?;
+
+        let mut descr = None;
+        let mut fortran_order = None;
+        let mut shape = None;
+        let mut parser = DictParser { rest: dict };
+        parser.expect('{')?;
+        while !parser.eat('}') {
+            let key = parser.string()?;
+            parser.expect(':')?;
+            match key {
+                "descr" => {
+                    let value = parser.string()?;
+                    descr = Some(Dtype::parse(value).ok_or_else(|| NpyError::UnsupportedDtype(value.to_string()))?);
+                }
+                "fortran_order" => fortran_order = Some(parser.boolean()?),
+                "shape" => shape = Some(parser.tuple()?),
+                _ => return Err(NpyError::InvalidHeader("unknown header key")),
+            }
+            if !parser.eat(',') {
+                parser.expect('}')?;
+                break;
+            }
+        }
+
+        let shape = shape.ok_or(NpyError::InvalidHeader("missing shape"))?;
+        if shape_len(&shape).is_none() {
+            return Err(NpyError::InvalidHeader("shape too large"));
+        }
+
+        Ok(Header {
+            version,
+            dtype: descr.ok_or(NpyError::InvalidHeader("missing descr"))?,
+            fortran_order: fortran_order.ok_or(NpyError::InvalidHeader("missing fortran_order"))?,
+            shape,
+            data_offset,
+        })
+    }
+
+    /// Get the amount of elements in the array.
+    ///
+    /// Parsed headers are refused if this would overflow, otherwise it saturates at `usize::MAX`.
+    pub fn element_count(&self) -> usize {
+        shape_len(&self.shape).unwrap_or(usize::MAX)
+    }
+}
+
+
+/// An error reading an `.npy` file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NpyError {
+    /// The header is malformed.
+    InvalidHeader(&'static str),
+    /// The format version isn't supported.
+    UnsupportedVersion(u8, u8),
+    /// The dtype isn't a simple one.
+    UnsupportedDtype(String),
+    /// The dtype doesn't describe the requested type.
+    DtypeMismatch {
+        /// The requested type's dtype.
+        expected: Dtype,
+        /// The file's dtype.
+        found: Dtype,
+    },
+    /// The dtype is not in native byte order, so the elements can't be viewed in place.
+    NonNativeEndianness(Dtype),
+    /// The amount of elements doesn't match the shape.
+    ShapeMismatch {
+        /// The amount of elements the shape describes.
+        expected: usize,
+        /// The amount of elements in the file.
+        found: usize,
+    },
+    /// The data section can't be viewed as the requested type.
+    Transmute(Error),
+}
+
+impl From<Error> for NpyError {
+    fn from(err: Error) -> NpyError {
+        NpyError::Transmute(err)
+    }
+}
+
+impl StdError for NpyError {}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NpyError::InvalidHeader(why) => write!(f, "Invalid .npy header: {}", why),
+            NpyError::UnsupportedVersion(major, minor) => write!(f, "Unsupported .npy version {}.{}", major, minor),
+            NpyError::UnsupportedDtype(ref descr) => write!(f, "Unsupported dtype {}", descr),
+            NpyError::DtypeMismatch { expected, found } => write!(f, "Dtype mismatch (expected: {}, found: {})", expected, found),
+            NpyError::NonNativeEndianness(dtype) => write!(f, "Dtype {} not in native byte order", dtype),
+            NpyError::ShapeMismatch { expected, found } => write!(f, "Element count doesn't match shape (expected: {}, found: {})", expected, found),
+            NpyError::Transmute(ref err) => err.fmt(f),
+        }
+    }
+}
+
+
+/// View the elements of an `.npy` file in place.
+///
+/// The dtype must match `T` and be in native byte order, and the data section must be suitably aligned for `T`,
+/// which it is if the file is, as the format pads the header to a multiple of 64 bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many, zeroed_vec, npy};
+/// let mut file = vec![];
+/// npy::write(&mut file, &[1u16, 2, 3], &[3]).unwrap();
+///
+/// // Copy into a buffer of u64s, so it's suitably aligned
+/// let mut buf = zeroed_vec::<u64>(file.len().div_ceil(8));
+/// guarded_transmute_to_bytes_pod_many_mut(&mut buf)[..file.len()].copy_from_slice(&file);
+/// let file = &guarded_transmute_to_bytes_pod_many(&buf)[..file.len()];
+///
+/// let (header, values) = npy::view::<u16>(file).unwrap();
+/// assert_eq!(header.shape, vec![3]);
+/// assert_eq!(values, &[1, 2, 3]);
+/// ```
+pub fn view<T: NpyType>(bytes: &[u8]) -> Result<(Header, &[T]), NpyError> {
+    let header = Header::parse(bytes)?;
+    check_dtype::<T>(&header.dtype)?;
+    if header.dtype.endianness.is_some_and(|e| !e.is_native()) {
+        return Err(NpyError::NonNativeEndianness(header.dtype));
+    }
+
+    let data = &bytes[header.data_offset..];
+    let expected = header.element_count();
+    if expected == 0 && data.is_empty() {
+        return Ok((header, &[]));
+    }
+
+    let values = guarded_transmute_pod_many_pedantic::<T>(data)?;
+    check_count(expected, values.len())?;
+    Ok((header, values))
+}
+
+/// Copy the elements of an `.npy` file out, converting them to native byte order.
+///
+/// The dtype must match `T`, but the data section needn't be aligned.
+pub fn decode<T: NpyType>(bytes: &[u8]) -> Result<(Header, Vec<T>), NpyError> {
+    let header = Header::parse(bytes)?;
+    check_dtype::<T>(&header.dtype)?;
+
+    let data = &bytes[header.data_offset..];
+    let expected = header.element_count();
+    if !(expected == 0 && data.is_empty()) {
+        check_count(expected, check_length::<T>(data)?)?;
+    }
+
+    let mut values = zeroed_vec::<T>(expected);
+    {
+        let dest = guarded_transmute_to_bytes_pod_many_mut(&mut values);
+        dest.copy_from_slice(data);
+        if header.dtype.endianness.is_some_and(|e| !e.is_native()) {
+            swap_elements(dest, size_of::<T>());
+        }
+    }
+    Ok((header, values))
+}
+
+/// Write an array with the specified shape in the `.npy` format, in native byte order and row-major order.
+///
+/// The shape must describe exactly as many elements as are in the slice, otherwise an `InvalidInput` error is returned.
+pub fn write<T: NpyType>(writer: &mut impl Write, data: &[T], shape: &[usize]) -> io::Result<()> {
+    let expected = shape_len(shape).unwrap_or(usize::MAX);
+    if expected != data.len() {
+        return Err(io::Error::new(ErrorKind::InvalidInput,
+                                  NpyError::ShapeMismatch {
+                                      expected,
+                                      found: data.len(),
+                                  }));
+    }
+
+    let shape = match shape.len() {
+        1 => format!("({},)", shape[0]),
+        _ => format!("({})", shape.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", ")),
+    };
+    let dict = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", Dtype::of::<T>(), shape);
+
+    let mut header = padded_header(&dict, 1);
+    if header.len() - (MAGIC.len() + 2 + 2) > u16::MAX as usize {
+        header = padded_header(&dict, 2);
+    }
+    writer.write_all(&header)?;
+    writer.write_all(guarded_transmute_to_bytes_pod_many(data))
+}
+
+
+/// Assemble the magic string, version, header length and header dictionary, padded so the data that follows is aligned.
+fn padded_header(dict: &str, major_version: u8) -> Vec<u8> {
+    let prefix_len = MAGIC.len() + 2 + if major_version == 1 { 2 } else { 4 };
+    let dict_len = (prefix_len + dict.len() + 1).div_ceil(DATA_ALIGNMENT) * DATA_ALIGNMENT - prefix_len;
+
+    let mut header = MAGIC.to_vec();
+    header.extend_from_slice(&[major_version, 0]);
+    if major_version == 1 {
+        header.extend_from_slice(&(dict_len as u16).to_le_bytes());
+    } else {
+        header.extend_from_slice(&(dict_len as u32).to_le_bytes());
+    }
+    header.extend_from_slice(dict.as_bytes());
+    header.resize(prefix_len + dict_len - 1, b' ');
+    header.push(b'\n');
+    header
+}
+
+
+fn check_dtype<T: NpyType>(dtype: &Dtype) -> Result<(), NpyError> {
+    if dtype.matches::<T>() {
+        Ok(())
+    } else {
+        Err(NpyError::DtypeMismatch {
+            expected: Dtype::of::<T>(),
+            found: *dtype,
+        })
+    }
+}
+
+/// Get the amount of elements in an array of the specified shape, or `None` if it overflows.
+fn shape_len(shape: &[usize]) -> Option<usize> {
+    shape.iter().try_fold(1usize, |acc, &d| acc.checked_mul(d))
+}
+
+fn check_count(expected: usize, found: usize) -> Result<(), NpyError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(NpyError::ShapeMismatch { expected, found })
+    }
+}
+
+/// Get the amount of `T`s in `data`, with the same checks as `guarded_transmute_pod_many_pedantic()`, but regardless of alignment.
+fn check_length<T>(data: &[u8]) -> Result<usize, Error> {
+    let size = size_of::<T>();
+    if data.len() < size {
+        Err(Error {
+            required: size,
+            actual: data.len(),
+            reason: ErrorReason::NotEnoughBytes,
+        })
+    } else if data.len() % size != 0 {
+        Err(Error {
+            required: size,
+            actual: data.len(),
+            reason: ErrorReason::InexactByteCount,
+        })
+    } else {
+        Ok(data.len() / size)
+    }
+}
+
+
+/// Parser for the subset of Python literal syntax used in `.npy` headers.
+struct DictParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> DictParser<'a> {
+    fn eat(&mut self, c: char) -> bool {
+        self.rest = self.rest.trim_start();
+        if self.rest.starts_with(c) {
+            self.rest = &self.rest[c.len_utf8()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), NpyError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(NpyError::InvalidHeader("unexpected character"))
+        }
+    }
+
+    fn string(&mut self) -> Result<&'a str, NpyError> {
+        let quote = if self.eat('\'') {
+            '\''
+        } else if self.eat('"') {
+            '"'
+        } else {
+            return Err(NpyError::InvalidHeader("expected string"));
+        };
+
+        let end = self.rest.find(quote).ok_or(NpyError::InvalidHeader("unterminated string"))?;
+        let value = &self.rest[..end];
+        self.rest = &self.rest[end + 1..];
+        Ok(value)
+    }
+
+    fn boolean(&mut self) -> Result<bool, NpyError> {
+        self.rest = self.rest.trim_start();
+        for &(word, value) in &[("True", true), ("False", false)] {
+            if self.rest.starts_with(word) {
+                self.rest = &self.rest[word.len()..];
+                return Ok(value);
+            }
+        }
+        Err(NpyError::InvalidHeader("expected boolean"))
+    }
+
+    fn tuple(&mut self) -> Result<Vec<usize>, NpyError> {
+        let mut values = vec![];
+        self.expect('(')?;
+        while !self.eat(')') {
+            self.rest = self.rest.trim_start();
+            let end = self.rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(self.rest.len());
+            values.push(self.rest[..end].parse().map_err(|_| NpyError::InvalidHeader("expected dimension"))?);
+            self.rest = &self.rest[end..];
+            // Python 2 long literals
+            self.eat('L');
+            if !self.eat(',') {
+                self.expect(')')?;
+                break;
+            }
+        }
+        Ok(values)
+    }
+}
@@ -1,5 +1,16 @@
-use self::super::{Error, guarded_transmute_vec_permissive, guarded_transmute_many_permissive, guarded_transmute_many_pedantic, guarded_transmute_vec_pedantic,
-                  guarded_transmute_pedantic, guarded_transmute_vec, guarded_transmute_many, guarded_transmute};
+use self::super::{ErrorReason, Error, check_alignment, guarded_transmute_many_permissive, guarded_transmute_many_pedantic, guarded_transmute_many_exact, guarded_transmute_many_max,
+                  guarded_transmute_pedantic, guarded_transmute_many, guarded_transmute};
+#[cfg(feature = "alloc")]
+use self::super::{VecError, guarded_transmute_vec_permissive, guarded_transmute_vec_pedantic, guarded_transmute_vec};
+#[cfg(feature = "alloc")]
+use self::super::{LayoutKind, TypeLayout};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::mem::{size_of_val, size_of};
+use core::ptr::{self, NonNull};
+use core::slice;
 
 
 /// Type that can be non-`unsafe`ly transmuted into
@@ -14,23 +25,52 @@ use self::super::{Error, guarded_transmute_vec_permissive, guarded_transmute_man
 ///
 /// *Nota bene*: `bool`s aren't *actually* non-`unsafe` to transmute, because they're restricted to being `0` or `1`,
 /// which means it's UB to transmute an arbitrary byte into a `bool`.
-pub trait PodTransmutable {}
-
-impl PodTransmutable for u8 {}
-impl PodTransmutable for i8 {}
-impl PodTransmutable for u16 {}
-impl PodTransmutable for i16 {}
-impl PodTransmutable for u32 {}
-impl PodTransmutable for i32 {}
-impl PodTransmutable for u64 {}
-impl PodTransmutable for i64 {}
-impl PodTransmutable for char {}
-impl PodTransmutable for f32 {}
-impl PodTransmutable for f64 {}
-#[cfg(i128_type)]
-impl PodTransmutable for u128 {}
-#[cfg(i128_type)]
-impl PodTransmutable for i128 {}
+///
+/// Structs can implement this trait with `pod_transmutable!()`, which also describes their fields in `layout()`.
+///
+/// # Safety
+///
+/// Every bit pattern of `size_of::<Self>()` bytes must be a valid instance of the implementing type,
+/// and it must not contain padding, since its bytes can be read back with `guarded_transmute_to_bytes_pod_many()`.
+/// This rules out e.g. references, `bool`, `NonZero*` integers, and `enum`s.
+pub unsafe trait PodTransmutable {
+    /// Describe this type's memory layout.
+    ///
+    /// By default, this only records the type's name, size and alignment, with an `Opaque` structure.
+    ///
+    /// Available with the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    fn layout() -> TypeLayout
+        where Self: Sized
+    {
+        TypeLayout::new::<Self>(LayoutKind::Opaque)
+    }
+}
+
+macro_rules! impl_pod_transmutable_primitive {
+    ($($ty:ty)*) => {
+        $(
+            unsafe impl PodTransmutable for $ty {
+                #[cfg(feature = "alloc")]
+                fn layout() -> TypeLayout {
+                    TypeLayout::new::<$ty>(LayoutKind::Primitive)
+                }
+            }
+        )*
+    }
+}
+
+impl_pod_transmutable_primitive!(u8 i8 u16 i16 u32 i32 u64 i64 f32 f64 u128 i128);
+
+unsafe impl<T: PodTransmutable, const N: usize> PodTransmutable for [T; N] {
+    #[cfg(feature = "alloc")]
+    fn layout() -> TypeLayout {
+        TypeLayout::new::<[T; N]>(LayoutKind::Array {
+            element: Box::new(T::layout()),
+            len: N,
+        })
+    }
+}
 
 
 /// Transmute a byte slice into a single instance of a POD.
@@ -76,9 +116,11 @@ pub fn guarded_transmute_pod_pedantic<T: PodTransmutable + Copy>(bytes: &[u8]) -
     unsafe { guarded_transmute_pedantic(bytes) }
 }
 
-/// Transmute a byte slice into a single instance of a POD.
+/// View a byte slice as a slice of a POD type.
 ///
-/// The byte slice must have exactly enough bytes to fill a single instance of a type.
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// extraneous data is ignored, and must be suitably aligned for `T`.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
 ///
 /// # Examples
 ///
@@ -100,7 +142,8 @@ pub fn guarded_transmute_pod_many<T: PodTransmutable>(bytes: &[u8]) -> Result<&[
 
 /// View a byte slice as a slice of a POD type.
 ///
-/// The resulting slice will have as many instances of a type as will fit, rounded down.
+/// The resulting slice will have as many instances of a type as will fit, rounded down,
+/// and will be empty if the byte slice isn't suitably aligned for `T`, or `T` is zero-sized.
 ///
 /// # Examples
 ///
@@ -115,7 +158,8 @@ pub fn guarded_transmute_pod_many_permissive<T: PodTransmutable>(bytes: &[u8]) -
 /// View a byte slice as a slice of POD.
 ///
 /// The byte slice must have at least enough bytes to fill a single instance of a type,
-/// and should not have extraneous data.
+/// should not have extraneous data, and must be suitably aligned for `T`.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
 ///
 /// # Examples
 ///
@@ -135,11 +179,264 @@ pub fn guarded_transmute_pod_many_pedantic<T: PodTransmutable>(bytes: &[u8]) ->
     unsafe { guarded_transmute_many_pedantic(bytes) }
 }
 
+/// View a byte slice as a slice of at most `max` POD instances.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// and at most enough to fill `max` instances; extraneous data past the last whole instance is ignored.
+/// It must be suitably aligned for `T`, and zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, guarded_transmute_pod_many_max, guarded_transmute_to_bytes_pod_many};
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2, 3]);
+/// assert_eq!(guarded_transmute_pod_many_max::<u16>(&bytes[..5], 2), Ok(&[1, 2][..]));
+/// assert_eq!(guarded_transmute_pod_many_max::<u16>(bytes, 2).unwrap_err().reason,
+///            ErrorReason::TooManyBytes);
+/// ```
+pub fn guarded_transmute_pod_many_max<T: PodTransmutable>(bytes: &[u8], max: usize) -> Result<&[T], Error> {
+    unsafe { guarded_transmute_many_max(bytes, max) }
+}
+
+/// View a byte slice as a slice of exactly `count` POD instances.
+///
+/// The byte slice must have exactly enough bytes to fill `count` instances of a type,
+/// and must be suitably aligned for `T`, unless `count` is `0` or `T` is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, guarded_transmute_pod_many_exact, guarded_transmute_to_bytes_pod_many};
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2, 3]);
+/// assert_eq!(guarded_transmute_pod_many_exact::<u16>(&bytes[..4], 2), Ok(&[1, 2][..]));
+/// assert_eq!(guarded_transmute_pod_many_exact::<u16>(&bytes[..5], 2).unwrap_err().reason,
+///            ErrorReason::TooManyBytes);
+/// ```
+pub fn guarded_transmute_pod_many_exact<T: PodTransmutable>(bytes: &[u8], count: usize) -> Result<&[T], Error> {
+    unsafe { guarded_transmute_many_exact(bytes, count) }
+}
+
+/// View a byte slice as a fixed-size array of POD.
+///
+/// The byte slice must have at least enough bytes to fill the array, extraneous data is ignored,
+/// and must be suitably aligned for `T`, unless the array is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_pod_array, guarded_transmute_to_bytes_pod_many};
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2, 3]);
+/// assert_eq!(guarded_transmute_pod_array::<u16, 2>(bytes), Ok(&[1, 2]));
+/// ```
+pub fn guarded_transmute_pod_array<T: PodTransmutable, const N: usize>(bytes: &[u8]) -> Result<&[T; N], Error> {
+    check_ref::<[T; N]>(bytes, false)?;
+    Ok(unsafe { &*ref_ptr(bytes.as_ptr() as *mut u8) })
+}
+
+/// View a byte slice as a fixed-size array of POD.
+///
+/// The byte slice must have exactly enough bytes to fill the array,
+/// and must be suitably aligned for `T`, unless the array is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, guarded_transmute_pod_array_pedantic, guarded_transmute_to_bytes_pod_many};
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2, 3]);
+/// assert_eq!(guarded_transmute_pod_array_pedantic::<u16, 3>(bytes), Ok(&[1, 2, 3]));
+/// assert_eq!(guarded_transmute_pod_array_pedantic::<u16, 2>(bytes).unwrap_err().reason, ErrorReason::InexactByteCount);
+/// ```
+pub fn guarded_transmute_pod_array_pedantic<T: PodTransmutable, const N: usize>(bytes: &[u8]) -> Result<&[T; N], Error> {
+    check_ref::<[T; N]>(bytes, true)?;
+    Ok(unsafe { &*ref_ptr(bytes.as_ptr() as *mut u8) })
+}
+
+/// View a mutable byte slice as a mutable fixed-size array of POD.
+///
+/// The byte slice must have at least enough bytes to fill the array, extraneous data is ignored,
+/// and must be suitably aligned for `T`, unless the array is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_pod_array_mut, guarded_transmute_to_bytes_pod_many_mut};
+/// let mut data = [0u32; 3];
+/// guarded_transmute_pod_array_mut::<u32, 2>(guarded_transmute_to_bytes_pod_many_mut(&mut data)).unwrap()[1] = 7;
+/// assert_eq!(data, [0, 7, 0]);
+/// ```
+pub fn guarded_transmute_pod_array_mut<T: PodTransmutable, const N: usize>(bytes: &mut [u8]) -> Result<&mut [T; N], Error> {
+    check_ref::<[T; N]>(bytes, false)?;
+    Ok(unsafe { &mut *ref_ptr(bytes.as_mut_ptr()) })
+}
+
+/// View a mutable byte slice as a mutable fixed-size array of POD.
+///
+/// The byte slice must have exactly enough bytes to fill the array,
+/// and must be suitably aligned for `T`, unless the array is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_pod_array_mut_pedantic, guarded_transmute_to_bytes_pod_many_mut};
+/// let mut data = [0u32; 3];
+/// *guarded_transmute_pod_array_mut_pedantic::<u32, 3>(guarded_transmute_to_bytes_pod_many_mut(&mut data)).unwrap() = [1, 2, 3];
+/// assert_eq!(data, [1, 2, 3]);
+/// ```
+pub fn guarded_transmute_pod_array_mut_pedantic<T: PodTransmutable, const N: usize>(bytes: &mut [u8]) -> Result<&mut [T; N], Error> {
+    check_ref::<[T; N]>(bytes, true)?;
+    Ok(unsafe { &mut *ref_ptr(bytes.as_mut_ptr()) })
+}
+
+/// Transmute a byte slice into a fixed-size array of POD.
+///
+/// The byte slice must have at least enough bytes to fill the array, extraneous data is ignored.
+/// It needn't be aligned.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::guarded_transmute_pod_array_owned;
+/// assert_eq!(guarded_transmute_pod_array_owned::<u8, 2>(&[0x01, 0x02, 0x03]), Ok([0x01, 0x02]));
+/// ```
+pub fn guarded_transmute_pod_array_owned<T: PodTransmutable + Copy, const N: usize>(bytes: &[u8]) -> Result<[T; N], Error> {
+    check_len::<[T; N]>(bytes, false)?;
+    Ok(unsafe { ptr::read_unaligned(bytes.as_ptr() as *const [T; N]) })
+}
+
+/// Transmute a byte slice into a fixed-size array of POD.
+///
+/// The byte slice must have exactly enough bytes to fill the array. It needn't be aligned.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, guarded_transmute_pod_array_owned_pedantic};
+/// assert_eq!(guarded_transmute_pod_array_owned_pedantic::<u8, 3>(&[0x01, 0x02, 0x03]), Ok([0x01, 0x02, 0x03]));
+/// assert_eq!(guarded_transmute_pod_array_owned_pedantic::<u8, 2>(&[0x01, 0x02, 0x03]).unwrap_err().reason,
+///            ErrorReason::InexactByteCount);
+/// ```
+pub fn guarded_transmute_pod_array_owned_pedantic<T: PodTransmutable + Copy, const N: usize>(bytes: &[u8]) -> Result<[T; N], Error> {
+    check_len::<[T; N]>(bytes, true)?;
+    Ok(unsafe { ptr::read_unaligned(bytes.as_ptr() as *const [T; N]) })
+}
+
+/// View a byte slice as a single instance of a POD, without copying it.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type, extraneous data is ignored,
+/// and must be suitably aligned for `T`, unless it's zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_pod_ref, guarded_transmute_to_bytes_pod_many};
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[[1u32; 64], [2u32; 64]]);
+/// assert_eq!(guarded_transmute_pod_ref::<[u32; 64]>(bytes).unwrap()[63], 1);
+/// ```
+pub fn guarded_transmute_pod_ref<T: PodTransmutable>(bytes: &[u8]) -> Result<&T, Error> {
+    check_ref::<T>(bytes, false)?;
+    Ok(unsafe { &*ref_ptr(bytes.as_ptr() as *mut u8) })
+}
+
+/// View a byte slice as a single instance of a POD, without copying it.
+///
+/// The byte slice must have exactly enough bytes to fill a single instance of a type,
+/// and must be suitably aligned for `T`, unless it's zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, guarded_transmute_pod_ref_pedantic, guarded_transmute_to_bytes_pod_many};
+/// let bytes = guarded_transmute_to_bytes_pod_many(&[1u32, 2]);
+/// assert_eq!(guarded_transmute_pod_ref_pedantic::<[u32; 2]>(bytes), Ok(&[1, 2]));
+/// assert_eq!(guarded_transmute_pod_ref_pedantic::<u32>(bytes).unwrap_err().reason, ErrorReason::InexactByteCount);
+/// ```
+pub fn guarded_transmute_pod_ref_pedantic<T: PodTransmutable>(bytes: &[u8]) -> Result<&T, Error> {
+    check_ref::<T>(bytes, true)?;
+    Ok(unsafe { &*ref_ptr(bytes.as_ptr() as *mut u8) })
+}
+
+/// View a mutable byte slice as a single mutable instance of a POD, without copying it.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type, extraneous data is ignored,
+/// and must be suitably aligned for `T`, unless it's zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_pod_ref_mut, guarded_transmute_to_bytes_pod_many_mut};
+/// let mut data = [0u32; 2];
+/// *guarded_transmute_pod_ref_mut::<u32>(guarded_transmute_to_bytes_pod_many_mut(&mut data)).unwrap() = 7;
+/// assert_eq!(data, [7, 0]);
+/// ```
+pub fn guarded_transmute_pod_ref_mut<T: PodTransmutable>(bytes: &mut [u8]) -> Result<&mut T, Error> {
+    check_ref::<T>(bytes, false)?;
+    Ok(unsafe { &mut *ref_ptr(bytes.as_mut_ptr()) })
+}
+
+/// View a mutable byte slice as a single mutable instance of a POD, without copying it.
+///
+/// The byte slice must have exactly enough bytes to fill a single instance of a type,
+/// and must be suitably aligned for `T`, unless it's zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{guarded_transmute_pod_ref_mut_pedantic, guarded_transmute_to_bytes_pod_many_mut};
+/// let mut data = [0u32; 2];
+/// guarded_transmute_pod_ref_mut_pedantic::<[u32; 2]>(guarded_transmute_to_bytes_pod_many_mut(&mut data)).unwrap()[1] = 7;
+/// assert_eq!(data, [0, 7]);
+/// ```
+pub fn guarded_transmute_pod_ref_mut_pedantic<T: PodTransmutable>(bytes: &mut [u8]) -> Result<&mut T, Error> {
+    check_ref::<T>(bytes, true)?;
+    Ok(unsafe { &mut *ref_ptr(bytes.as_mut_ptr()) })
+}
+
+/// Split a byte slice into an unaligned head, the largest aligned middle viewed as POD, and an unaligned tail.
+///
+/// This is the non-`unsafe` equivalent of `<[u8]>::align_to()`, and likewise the head may be longer than necessary,
+/// and for zero-sized types, all bytes are in the head.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::pod_align_to;
+/// let bytes = [0u8; 19];
+/// let (head, body, tail) = pod_align_to::<u32>(&bytes);
+/// assert!(head.len() < 4 && tail.len() < 4);
+/// assert_eq!(head.len() + body.len() * 4 + tail.len(), 19);
+/// ```
+pub fn pod_align_to<T: PodTransmutable>(bytes: &[u8]) -> (&[u8], &[T], &[u8]) {
+    unsafe { bytes.align_to() }
+}
+
+/// Split a mutable byte slice into an unaligned head, the largest aligned middle viewed as POD, and an unaligned tail.
+///
+/// This is the non-`unsafe` equivalent of `<[u8]>::align_to_mut()`, and likewise the head may be longer than necessary,
+/// and for zero-sized types, all bytes are in the head.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::pod_align_to_mut;
+/// let mut bytes = [0u8; 19];
+/// {
+///     let (head, body, tail) = pod_align_to_mut::<u32>(&mut bytes);
+///     head.iter_mut().chain(tail).for_each(|b| *b = 0xFF);
+///     body.iter_mut().for_each(|e| *e = 0x01010101);
+/// }
+/// assert!(bytes.iter().all(|&b| b == 0xFF || b == 0x01));
+/// ```
+pub fn pod_align_to_mut<T: PodTransmutable>(bytes: &mut [u8]) -> (&mut [u8], &mut [T], &mut [u8]) {
+    unsafe { bytes.align_to_mut() }
+}
+
 /// Trasform a byte vector into a vector of POD.
 ///
 /// The resulting vec will reuse the allocated byte buffer when possible, and
 /// should have at least enough bytes to fill a single instance of a type.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
 /// Extraneous data is ignored.
+/// On error, the byte vector is handed back in the `VecError`.
 ///
 /// # Examples
 ///
@@ -162,14 +459,15 @@ pub fn guarded_transmute_pod_many_pedantic<T: PodTransmutable>(bytes: &[u8]) ->
 /// assert!(guarded_transmute_pod_vec::<i16>(vec![0xED]).is_err());
 /// # }
 /// ```
-pub fn guarded_transmute_pod_vec<T: PodTransmutable>(bytes: Vec<u8>) -> Result<Vec<T>, Error> {
+#[cfg(feature = "alloc")]
+pub fn guarded_transmute_pod_vec<T: PodTransmutable>(bytes: Vec<u8>) -> Result<Vec<T>, VecError> {
     unsafe { guarded_transmute_vec(bytes) }
 }
 
 /// Trasform a byte vector into a vector of POD.
 ///
 /// The vector's allocated byte buffer will be reused when possible, and
-/// have as many instances of a type as will fit, rounded down.
+/// have as many instances of a type as will fit, rounded down, or none if `T` is zero-sized.
 /// Extraneous data is ignored.
 ///
 /// # Examples
@@ -192,6 +490,7 @@ pub fn guarded_transmute_pod_vec<T: PodTransmutable>(bytes: Vec<u8>) -> Result<V
 /// assert_eq!(guarded_transmute_pod_vec_permissive::<u16>(vec![0xED]), vec![]);
 /// # }
 /// ```
+#[cfg(feature = "alloc")]
 pub fn guarded_transmute_pod_vec_permissive<T: PodTransmutable>(bytes: Vec<u8>) -> Vec<T> {
     unsafe { guarded_transmute_vec_permissive(bytes) }
 }
@@ -201,6 +500,8 @@ pub fn guarded_transmute_pod_vec_permissive<T: PodTransmutable>(bytes: Vec<u8>)
 ///
 /// The vector's allocated byte buffer will be reused when possible, and
 /// should not have extraneous data.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+/// On error, the byte vector is handed back in the `VecError`.
 ///
 /// # Examples
 ///
@@ -219,6 +520,75 @@ pub fn guarded_transmute_pod_vec_permissive<T: PodTransmutable>(bytes: Vec<u8>)
 ///           .is_err());
 /// # }
 /// ```
-pub fn guarded_transmute_pod_vec_pedantic<T: PodTransmutable>(bytes: Vec<u8>) -> Result<Vec<T>, Error> {
+#[cfg(feature = "alloc")]
+pub fn guarded_transmute_pod_vec_pedantic<T: PodTransmutable>(bytes: Vec<u8>) -> Result<Vec<T>, VecError> {
     unsafe { guarded_transmute_vec_pedantic(bytes) }
 }
+
+/// View a slice of POD as its underlying bytes, in native byte order.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::guarded_transmute_to_bytes_pod_many;
+/// assert_eq!(guarded_transmute_to_bytes_pod_many(&[0x0102u16, 0x0304u16]),
+///            &[0x0102u16.to_ne_bytes(), 0x0304u16.to_ne_bytes()].concat()[..]);
+/// ```
+pub fn guarded_transmute_to_bytes_pod_many<T: PodTransmutable>(from: &[T]) -> &[u8] {
+    unsafe { slice::from_raw_parts(from.as_ptr() as *const u8, size_of_val(from)) }
+}
+
+/// View a mutable slice of POD as its underlying bytes, in native byte order.
+///
+/// Any bytes written through the view are valid, so this can be used to fill POD buffers from byte-oriented sources.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::guarded_transmute_to_bytes_pod_many_mut;
+/// let mut values = [0u16; 2];
+/// guarded_transmute_to_bytes_pod_many_mut(&mut values).copy_from_slice(&[0xFF; 4]);
+/// assert_eq!(values, [0xFFFF, 0xFFFF]);
+/// ```
+pub fn guarded_transmute_to_bytes_pod_many_mut<T: PodTransmutable>(from: &mut [T]) -> &mut [u8] {
+    unsafe { slice::from_raw_parts_mut(from.as_mut_ptr() as *mut u8, size_of_val(from)) }
+}
+
+
+/// Check that `bytes` can be viewed as a `T`, requiring an exact fit if `pedantic`.
+fn check_ref<T>(bytes: &[u8], pedantic: bool) -> Result<(), Error> {
+    check_len::<T>(bytes, pedantic)?;
+    if size_of::<T>() != 0 {
+        check_alignment::<T>(bytes)?;
+    }
+    Ok(())
+}
+
+/// Check that `bytes` can fill a `T`, requiring an exact fit if `pedantic`.
+fn check_len<T>(bytes: &[u8], pedantic: bool) -> Result<(), Error> {
+    let required = size_of::<T>();
+    if bytes.len() < required {
+        return Err(Error {
+            required,
+            actual: bytes.len(),
+            reason: ErrorReason::NotEnoughBytes,
+        });
+    }
+    if pedantic && bytes.len() != required {
+        return Err(Error {
+            required,
+            actual: bytes.len(),
+            reason: ErrorReason::InexactByteCount,
+        });
+    }
+    Ok(())
+}
+
+/// Get a pointer to the `T` at `ptr`, which must have passed `check_ref()`.
+fn ref_ptr<T>(ptr: *mut u8) -> *mut T {
+    if size_of::<T>() == 0 {
+        NonNull::dangling().as_ptr()
+    } else {
+        ptr as *mut T
+    }
+}
@@ -0,0 +1,171 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::{fmt, ptr};
+use self::super::{ErrorReason, Error, PodTransmutable, check_not_zero_sized};
+
+
+/// Iterate over POD values in a byte slice, by value.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// extraneous data is left in the iterator's `remainder()`.
+///
+/// The values needn't be aligned, so they're read by value.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::pod_iter;
+/// let bytes = [1u16.to_ne_bytes(), 2u16.to_ne_bytes(), [0xFF, 0xFF]].concat();
+/// let values = pod_iter::<u16>(&bytes[..5]).unwrap();
+/// assert_eq!(values.remainder(), &[0xFF]);
+/// assert_eq!(values.rev().collect::<Vec<_>>(), vec![2, 1]);
+/// ```
+pub fn pod_iter<T: PodTransmutable + Copy>(bytes: &[u8]) -> Result<PodIter<'_, T>, Error> {
+    check_not_zero_sized::<T>(bytes)?;
+    let ret = pod_iter_permissive(bytes);
+    if ret.len() == 0 {
+        Err(Error {
+            required: size_of::<T>(),
+            actual: bytes.len(),
+            reason: ErrorReason::NotEnoughBytes,
+        })
+    } else {
+        Ok(ret)
+    }
+}
+
+/// Iterate over POD values in a byte slice, by value.
+///
+/// The resulting iterator will have as many values as will fit, rounded down,
+/// extraneous data is left in its `remainder()`.
+/// It will be empty if `T` is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::pod_iter_permissive;
+/// let values = pod_iter_permissive::<u32>(&[0x00, 0x01]);
+/// assert_eq!(values.len(), 0);
+/// assert_eq!(values.remainder(), &[0x00, 0x01]);
+/// ```
+pub fn pod_iter_permissive<T: PodTransmutable + Copy>(bytes: &[u8]) -> PodIter<'_, T> {
+    let (bytes, remainder) = match bytes.len().checked_rem(size_of::<T>()) {
+        Some(extra) => bytes.split_at(bytes.len() - extra),
+        None => (&[][..], bytes),
+    };
+    PodIter {
+        bytes,
+        remainder,
+        _type: PhantomData,
+    }
+}
+
+/// Iterate over POD values in a byte slice, by value.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// and should not have extraneous data.
+/// Zero-sized types are refused with `ErrorReason::ZeroSizedType`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, pod_iter_pedantic};
+/// let bytes = [1u16.to_ne_bytes(), 2u16.to_ne_bytes()].concat();
+/// assert_eq!(pod_iter_pedantic::<u16>(&bytes).unwrap().nth(1), Some(2));
+/// assert_eq!(pod_iter_pedantic::<u16>(&bytes[1..]).unwrap_err().reason, ErrorReason::InexactByteCount);
+/// ```
+pub fn pod_iter_pedantic<T: PodTransmutable + Copy>(bytes: &[u8]) -> Result<PodIter<'_, T>, Error> {
+    let ret = pod_iter(bytes)?;
+    if !ret.remainder.is_empty() {
+        Err(Error {
+            required: size_of::<T>(),
+            actual: bytes.len(),
+            reason: ErrorReason::InexactByteCount,
+        })
+    } else {
+        Ok(ret)
+    }
+}
+
+
+/// Iterator over POD values in a byte slice, by value.
+///
+/// Created by `pod_iter()`, `pod_iter_permissive()` and `pod_iter_pedantic()`.
+pub struct PodIter<'a, T: PodTransmutable> {
+    bytes: &'a [u8],
+    remainder: &'a [u8],
+    _type: PhantomData<T>,
+}
+
+impl<'a, T: PodTransmutable> PodIter<'a, T> {
+    /// Get the bytes left over after the last whole value, which will never be yielded.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.remainder
+    }
+}
+
+impl<'a, T: PodTransmutable> Clone for PodIter<'a, T> {
+    fn clone(&self) -> PodIter<'a, T> {
+        PodIter { ..*self }
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> fmt::Debug for PodIter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PodIter")
+            .field("len", &self.len())
+            .field("remainder", &self.remainder)
+            .finish()
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> Iterator for PodIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.bytes.is_empty() {
+            None
+        } else {
+            let (value, rest) = self.bytes.split_at(size_of::<T>());
+            self.bytes = rest;
+            Some(unsafe { ptr::read_unaligned(value.as_ptr() as *const T) })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len().checked_div(size_of::<T>()).unwrap_or(0);
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let skip = n.saturating_mul(size_of::<T>()).min(self.bytes.len());
+        self.bytes = &self.bytes[skip..];
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<T> {
+        self.next_back()
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> DoubleEndedIterator for PodIter<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.bytes.is_empty() {
+            None
+        } else {
+            let (rest, value) = self.bytes.split_at(self.bytes.len() - size_of::<T>());
+            self.bytes = rest;
+            Some(unsafe { ptr::read_unaligned(value.as_ptr() as *const T) })
+        }
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> ExactSizeIterator for PodIter<'a, T> {}
+
+impl<'a, T: PodTransmutable + Copy> FusedIterator for PodIter<'a, T> {}
@@ -0,0 +1,275 @@
+use std::io::{self, ErrorKind, Read};
+use std::convert::TryFrom;
+use std::mem::size_of;
+use std::slice;
+use self::super::{DetailedError, ErrorReason, Error, PodTransmutable, guarded_transmute_to_bytes_pod_many_mut, zeroed_vec, zeroed};
+
+
+/// What a `PodReader` does with bytes at the end of a stream which don't form a whole record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartialRecord {
+    /// Fail with an `InexactByteCount` error, like the `*_pedantic()` functions.
+    Pedantic,
+    /// Drop the trailing bytes, like the `*_permissive()` functions.
+    Permissive,
+}
+
+/// Reader adapter yielding POD records from a byte stream.
+///
+/// The records are read into an internal buffer of `T`s, so they're always correctly aligned,
+/// and can be consumed either in batches, with `next_batch()`, or one-by-one, with `read_record()` or as an `Iterator`.
+///
+/// A trailing partial record is handled according to the reader's `PartialRecord` mode,
+/// and reported as an `io::Error` of kind `InvalidData` wrapping an `InexactByteCount` `DetailedError`
+/// with the record's offset in the stream and index; `Error::from_io_error()` gets the plain `Error` back out of it.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::PodReader;
+/// # use std::io::Cursor;
+/// let data: Vec<u8> = [1u32, 2, 3, 4, 5].iter().flat_map(|v| v.to_ne_bytes()).collect();
+/// let mut reader = PodReader::<_, u32>::with_capacity(2, Cursor::new(data));
+///
+/// assert_eq!(reader.next_batch().unwrap(), &[1, 2]);
+/// assert_eq!(reader.read_record().unwrap(), Some(3));
+/// assert_eq!(reader.collect::<Result<Vec<_>, _>>().unwrap(), vec![4, 5]);
+/// ```
+pub struct PodReader<R: Read, T: PodTransmutable> {
+    inner: R,
+    buf: Vec<T>,
+    /// Byte offset of the first unconsumed record, always a multiple of `size_of::<T>()`.
+    start: usize,
+    /// Amount of bytes in `buf` filled from `inner`.
+    end: usize,
+    partial: PartialRecord,
+    records_read: u64,
+}
+
+impl<R: Read, T: PodTransmutable> PodReader<R, T> {
+    /// Create a pedantic reader with an 8 KiB buffer.
+    ///
+    /// # Panics
+    ///
+    /// If `T` is zero-sized.
+    pub fn new(inner: R) -> PodReader<R, T> {
+        PodReader::with_capacity((8 * 1024 / size_of::<T>().max(1)).max(1), inner)
+    }
+
+    /// Create a pedantic reader with a buffer for `capacity` records.
+    ///
+    /// # Panics
+    ///
+    /// If `T` is zero-sized, or `capacity` is `0`.
+    pub fn with_capacity(capacity: usize, inner: R) -> PodReader<R, T> {
+        assert!(size_of::<T>() != 0, "PodReader can't read zero-sized records");
+        assert!(capacity != 0, "PodReader needs space for at least one record");
+
+        PodReader {
+            inner,
+            buf: zeroed_vec(capacity),
+            start: 0,
+            end: 0,
+            partial: PartialRecord::Pedantic,
+            records_read: 0,
+        }
+    }
+
+    /// Set how a trailing partial record is handled, `Pedantic` by default.
+    pub fn set_partial_record(&mut self, partial: PartialRecord) {
+        self.partial = partial;
+    }
+
+    /// Get how a trailing partial record is handled.
+    pub fn partial_record(&self) -> PartialRecord {
+        self.partial
+    }
+
+    /// Get the amount of records yielded so far.
+    pub fn records_read(&self) -> u64 {
+        self.records_read
+    }
+
+    /// Get a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying reader.
+    ///
+    /// Reading from it directly will desynchronise the record stream.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap this reader, discarding any buffered data.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read the next batch of records.
+    ///
+    /// The batch contains all buffered records, or, if there are none, as many as a single refill yielded.
+    /// An empty batch signals the end of the stream.
+    pub fn next_batch(&mut self) -> io::Result<&[T]> {
+        let size = size_of::<T>();
+        if self.end - self.start < size {
+            self.fill()?;
+        }
+
+        let first = self.start / size;
+        let count = (self.end - self.start) / size;
+        self.start += count * size;
+        self.records_read += count as u64;
+        Ok(&self.buf[first..first + count])
+    }
+
+    /// Read a single record, or `None` at the end of the stream.
+    pub fn read_record(&mut self) -> io::Result<Option<T>>
+        where T: Copy
+    {
+        let size = size_of::<T>();
+        if self.end - self.start < size {
+            self.fill()?;
+            if self.end - self.start < size {
+                return Ok(None);
+            }
+        }
+
+        let record = self.buf[self.start / size];
+        self.start += size;
+        self.records_read += 1;
+        Ok(Some(record))
+    }
+
+    /// Move the unconsumed bytes to the front of the buffer and read until at least one whole record is available.
+    ///
+    /// At the end of the stream, any partial record is handled according to `self.partial`.
+    fn fill(&mut self) -> io::Result<()> {
+        let size = size_of::<T>();
+        let bytes = guarded_transmute_to_bytes_pod_many_mut(&mut self.buf);
+
+        if self.start != 0 {
+            bytes.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+
+        while self.end < size {
+            match self.inner.read(&mut bytes[self.end..]) {
+                Ok(0) => {
+                    let leftover = self.end;
+                    self.end = 0;
+                    return match (leftover, self.partial) {
+                        (0, _) |
+                        (_, PartialRecord::Permissive) => Ok(()),
+                        (_, PartialRecord::Pedantic) => {
+                            let err = Error {
+                                required: size,
+                                actual: leftover,
+                                reason: ErrorReason::InexactByteCount,
+                            };
+                            // Saturate rather than truncate or wrap for streams longer than usize::MAX bytes
+                            let index = usize::try_from(self.records_read).unwrap_or(usize::MAX);
+                            Err(DetailedError::new::<T>(err, &bytes[..leftover])
+                                .at_offset(index.saturating_mul(size))
+                                .at_index(index)
+                                .into())
+                        }
+                    };
+                }
+                Ok(read) => self.end += read,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read, T: PodTransmutable + Copy> Iterator for PodReader<R, T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<io::Result<T>> {
+        match self.read_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+
+/// Read a single instance of a POD from a byte stream.
+///
+/// The value is read in native byte order, directly into its own storage.
+///
+/// If the stream ends before the value is complete, an `io::Error` of kind `UnexpectedEof` wrapping
+/// a `NotEnoughBytes` `DetailedError` with the amount of bytes read is returned.
+/// `Error::from_io_error()` gets the plain `Error` back out of it.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{DetailedError, ErrorReason, Error, read_pod};
+/// let mut stream = &0x01020304u32.to_ne_bytes()[..];
+/// assert_eq!(read_pod::<u32>(&mut stream).unwrap(), 0x01020304);
+///
+/// let err = read_pod::<u64>(&mut &[0x00, 0x01][..]).unwrap_err();
+/// assert_eq!(err.get_ref().and_then(|e| e.downcast_ref::<DetailedError>()).map(|e| e.error),
+///            Some(Error {
+///                required: 64 / 8,
+///                actual: 2,
+///                reason: ErrorReason::NotEnoughBytes,
+///            }));
+/// ```
+pub fn read_pod<T: PodTransmutable + Copy>(reader: &mut (impl Read + ?Sized)) -> io::Result<T> {
+    let mut value: T = zeroed();
+    read_pod_into(reader, slice::from_mut(&mut value))?;
+    Ok(value)
+}
+
+/// Fill a slice of POD from a byte stream.
+///
+/// The values are read in native byte order, directly into the slice.
+///
+/// If the stream ends before the slice is filled, an `io::Error` of kind `UnexpectedEof` wrapping
+/// a `NotEnoughBytes` `DetailedError` with the amount of bytes read, and the offset and index of the incomplete element,
+/// is returned; the contents of the slice are then unspecified.
+/// `Error::from_io_error()` gets the plain `Error` back out of it.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::read_pod_into;
+/// let bytes = [1u16.to_ne_bytes(), 2u16.to_ne_bytes(), 3u16.to_ne_bytes()].concat();
+///
+/// let mut values = [0u16; 3];
+/// read_pod_into(&mut &bytes[..], &mut values).unwrap();
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn read_pod_into<T: PodTransmutable>(reader: &mut (impl Read + ?Sized), into: &mut [T]) -> io::Result<()> {
+    let bytes = guarded_transmute_to_bytes_pod_many_mut(into);
+
+    let mut filled = 0;
+    while filled < bytes.len() {
+        match reader.read(&mut bytes[filled..]) {
+            Ok(0) => {
+                let err = Error {
+                    required: bytes.len(),
+                    actual: filled,
+                    reason: ErrorReason::NotEnoughBytes,
+                };
+                let index = filled / size_of::<T>();
+                let partial = index * size_of::<T>();
+                return Err(DetailedError::new::<T>(err, &bytes[partial..filled]).at_offset(partial).at_index(index).into());
+            }
+            Ok(read) => filled += read,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,381 @@
+use core::mem::{align_of, size_of};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::ops::Index;
+use core::{fmt, slice};
+use self::super::{ErrorReason, Error, PodTransmutable, guarded_transmute_to_bytes_pod_many, check_alignment};
+
+
+/// A two-dimensional, row-major view of bytes as a POD matrix.
+///
+/// Rows start `pitch` bytes apart, which may be more than a row's worth of elements to allow for padding,
+/// like in image data. The padding after the last row may be omitted.
+///
+/// The bytes must be suitably aligned for `T`, unless there are none.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PodMatrix, guarded_transmute_to_bytes_pod_many};
+/// // 2x3 u16s, with rows padded to 8 bytes
+/// let data = [1u16, 2, 3, 0, 4, 5, 6, 0];
+/// let bytes = guarded_transmute_to_bytes_pod_many(&data);
+///
+/// let matrix = PodMatrix::<u16>::with_pitch(bytes, 2, 3, 8).unwrap();
+/// assert_eq!(matrix.row(1), Some(&[4, 5, 6][..]));
+/// assert_eq!(matrix[(0, 2)], 3);
+/// assert_eq!(matrix.rows().map(|r| r.iter().sum::<u16>()).collect::<Vec<_>>(), vec![6, 15]);
+/// ```
+pub struct PodMatrix<'a, T: PodTransmutable> {
+    bytes: &'a [u8],
+    rows: usize,
+    cols: usize,
+    pitch: usize,
+    _type: PhantomData<&'a [T]>,
+}
+
+impl<'a, T: PodTransmutable> PodMatrix<'a, T> {
+    /// View the specified bytes as a matrix of `rows` by `cols` tightly-packed elements.
+    ///
+    /// The byte count must match the shape exactly.
+    /// If a row's byte count overflows `usize`, the error's `required` is `usize::MAX`.
+    pub fn new(bytes: &'a [u8], rows: usize, cols: usize) -> Result<PodMatrix<'a, T>, Error> {
+        match cols.checked_mul(size_of::<T>()) {
+            Some(pitch) => PodMatrix::with_pitch(bytes, rows, cols, pitch),
+            None => {
+                Err(Error {
+                    required: usize::MAX,
+                    actual: bytes.len(),
+                    reason: ErrorReason::NotEnoughBytes,
+                })
+            }
+        }
+    }
+
+    /// View the specified bytes as a matrix of `rows` by `cols` elements, with rows starting `pitch` bytes apart.
+    ///
+    /// The byte count must be at least enough to fill the last row, and at most `rows * pitch`.
+    /// If the former overflows `usize`, the error's `required` is `usize::MAX`.
+    ///
+    /// A `pitch` shorter than a row is refused with `ErrorReason::InvalidStride`,
+    /// and one that isn't a multiple of `T`'s alignment with `ErrorReason::UnalignedMemory`,
+    /// with `actual` the remainder.
+    pub fn with_pitch(bytes: &'a [u8], rows: usize, cols: usize, pitch: usize) -> Result<PodMatrix<'a, T>, Error> {
+        let row_len = match cols.checked_mul(size_of::<T>()) {
+            Some(row_len) if row_len <= pitch => row_len,
+            row_len => {
+                return Err(Error {
+                    required: row_len.unwrap_or(usize::MAX),
+                    actual: pitch,
+                    reason: ErrorReason::InvalidStride,
+                })
+            }
+        };
+        if pitch % align_of::<T>() != 0 {
+            return Err(Error {
+                required: align_of::<T>(),
+                actual: pitch % align_of::<T>(),
+                reason: ErrorReason::UnalignedMemory,
+            });
+        }
+
+        let required = if rows == 0 {
+            0
+        } else {
+            (rows - 1).saturating_mul(pitch).saturating_add(row_len)
+        };
+        if bytes.len() < required {
+            return Err(Error {
+                required,
+                actual: bytes.len(),
+                reason: ErrorReason::NotEnoughBytes,
+            });
+        }
+        let max = rows.saturating_mul(pitch);
+        if bytes.len() > max {
+            return Err(Error {
+                required: max,
+                actual: bytes.len(),
+                reason: ErrorReason::InexactByteCount,
+            });
+        }
+        if !bytes.is_empty() {
+            check_alignment::<T>(bytes)?;
+        }
+
+        Ok(PodMatrix {
+            bytes,
+            rows,
+            cols,
+            pitch,
+            _type: PhantomData,
+        })
+    }
+
+    /// Get the amount of rows.
+    pub fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the amount of elements in each row.
+    pub fn col_count(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the distance between the starts of consecutive rows in bytes.
+    pub fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    /// Get the row at the specified index, or `None` if out of bounds.
+    pub fn row(&self, row: usize) -> Option<&'a [T]> {
+        if row < self.rows {
+            Some(unsafe { row_at(self.bytes, row * self.pitch, self.cols) })
+        } else {
+            None
+        }
+    }
+
+    /// Get the element at the specified row and column, or `None` if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        self.row(row).and_then(|r| r.get(col))
+    }
+
+    /// Iterate over the rows.
+    pub fn rows(&self) -> PodRows<'a, T> {
+        PodRows {
+            bytes: self.bytes,
+            cols: self.cols,
+            pitch: self.pitch,
+            front: 0,
+            back: self.rows,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: PodTransmutable> Index<(usize, usize)> for PodMatrix<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.get(row, col) {
+            Some(e) => e,
+            None => panic!("index ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols),
+        }
+    }
+}
+
+impl<'a, T: PodTransmutable> Clone for PodMatrix<'a, T> {
+    fn clone(&self) -> PodMatrix<'a, T> {
+        *self
+    }
+}
+
+impl<'a, T: PodTransmutable> Copy for PodMatrix<'a, T> {}
+
+impl<'a, T: PodTransmutable> fmt::Debug for PodMatrix<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PodMatrix")
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .field("pitch", &self.pitch)
+            .finish()
+    }
+}
+
+
+/// An N-dimensional, row-major view of tightly-packed bytes as a POD array.
+///
+/// The bytes must be suitably aligned for `T`, unless there are none.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PodArrayView, guarded_transmute_to_bytes_pod_many};
+/// let data: Vec<u32> = (0..24).collect();
+/// let array = PodArrayView::<u32, 3>::new(guarded_transmute_to_bytes_pod_many(&data), [2, 3, 4]).unwrap();
+///
+/// assert_eq!(array.len(), 24);
+/// assert_eq!(array[[1, 2, 3]], 23);
+/// assert_eq!(array.get([1, 3, 0]), None);
+/// assert_eq!(array.rows().nth(4), Some(&[16, 17, 18, 19][..]));
+/// ```
+pub struct PodArrayView<'a, T: PodTransmutable, const D: usize> {
+    data: &'a [T],
+    shape: [usize; D],
+}
+
+impl<'a, T: PodTransmutable, const D: usize> PodArrayView<'a, T, D> {
+    /// View the specified bytes as an array of the specified shape.
+    ///
+    /// The byte count must match the shape exactly.
+    /// If the shape's element or byte count overflows `usize`, the error's `required` is `usize::MAX`.
+    pub fn new(bytes: &'a [u8], shape: [usize; D]) -> Result<PodArrayView<'a, T, D>, Error> {
+        let (len, required) = match shape.iter().try_fold(1usize, |len, &dim| len.checked_mul(dim)) {
+            Some(len) => (len, len.saturating_mul(size_of::<T>())),
+            None => (usize::MAX, usize::MAX),
+        };
+        if bytes.len() != required {
+            return Err(Error {
+                required,
+                actual: bytes.len(),
+                reason: if bytes.len() < required {
+                    ErrorReason::NotEnoughBytes
+                } else {
+                    ErrorReason::InexactByteCount
+                },
+            });
+        }
+        if !bytes.is_empty() {
+            check_alignment::<T>(bytes)?;
+        }
+
+        Ok(PodArrayView {
+            data: unsafe { row_at(bytes, 0, len) },
+            shape,
+        })
+    }
+
+    /// Get the length of each dimension.
+    pub fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    /// Get the total amount of elements.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get all elements, in row-major order.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// Get the element at the specified index, or `None` if out of bounds.
+    pub fn get(&self, index: [usize; D]) -> Option<&'a T> {
+        let mut offset = 0;
+        for (&i, &len) in index.iter().zip(self.shape.iter()) {
+            if i >= len {
+                return None;
+            }
+            offset = offset * len + i;
+        }
+        self.data.get(offset)
+    }
+
+    /// Iterate over the rows, i.e. the runs of elements along the last dimension.
+    ///
+    /// A zero-dimensional array has a single one-element row.
+    pub fn rows(&self) -> PodRows<'a, T> {
+        // Can't overflow, since new() multiplied the dimensions in order without overflowing
+        let (cols, rows) = match self.shape.split_last() {
+            Some((&cols, rest)) => (cols, rest.iter().product()),
+            None => (1, 1),
+        };
+        PodRows {
+            bytes: guarded_transmute_to_bytes_pod_many(self.data),
+            cols,
+            pitch: cols * size_of::<T>(),
+            front: 0,
+            back: rows,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: PodTransmutable, const D: usize> Index<[usize; D]> for PodArrayView<'a, T, D> {
+    type Output = T;
+
+    fn index(&self, index: [usize; D]) -> &T {
+        match self.get(index) {
+            Some(e) => e,
+            None => panic!("index {:?} out of bounds for shape {:?}", index, self.shape),
+        }
+    }
+}
+
+impl<'a, T: PodTransmutable, const D: usize> Clone for PodArrayView<'a, T, D> {
+    fn clone(&self) -> PodArrayView<'a, T, D> {
+        *self
+    }
+}
+
+impl<'a, T: PodTransmutable, const D: usize> Copy for PodArrayView<'a, T, D> {}
+
+impl<'a, T: PodTransmutable, const D: usize> fmt::Debug for PodArrayView<'a, T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PodArrayView").field("shape", &self.shape).finish()
+    }
+}
+
+
+/// Iterator over the rows of a `PodMatrix` or `PodArrayView`.
+pub struct PodRows<'a, T: PodTransmutable> {
+    bytes: &'a [u8],
+    cols: usize,
+    pitch: usize,
+    front: usize,
+    back: usize,
+    _type: PhantomData<&'a [T]>,
+}
+
+impl<'a, T: PodTransmutable> Clone for PodRows<'a, T> {
+    fn clone(&self) -> PodRows<'a, T> {
+        PodRows { ..*self }
+    }
+}
+
+impl<'a, T: PodTransmutable> Iterator for PodRows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(unsafe { row_at(self.bytes, (self.front - 1) * self.pitch, self.cols) })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a [T]> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<'a, T: PodTransmutable> DoubleEndedIterator for PodRows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { row_at(self.bytes, self.back * self.pitch, self.cols) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: PodTransmutable> ExactSizeIterator for PodRows<'a, T> {}
+
+impl<'a, T: PodTransmutable> FusedIterator for PodRows<'a, T> {}
+
+
+/// View `len` elements at `offset` in `bytes`, which must be in bounds and aligned for `T`.
+///
+/// Zero-sized and empty rows don't occupy any bytes, which may then not be aligned, so they use a dangling pointer.
+unsafe fn row_at<T>(bytes: &[u8], offset: usize, len: usize) -> &[T] {
+    if size_of::<T>() == 0 || len == 0 {
+        slice::from_raw_parts(NonNull::dangling().as_ptr(), len)
+    } else {
+        slice::from_raw_parts(bytes.as_ptr().add(offset) as *const T, len)
+    }
+}
@@ -0,0 +1,240 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::{fmt, ptr};
+use self::super::{ErrorReason, Error, PodTransmutable};
+
+
+/// A view of every `stride`th group of bytes in a buffer, starting at `offset`, as a POD.
+///
+/// This is the shape of one field in a buffer of interleaved records, like the position in a vertex buffer.
+///
+/// The elements needn't be aligned, so they're read by value.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::StridedSlice;
+/// // Records of a u16 followed by a u32, unpadded
+/// let mut bytes = vec![];
+/// for i in 0..3u16 {
+///     bytes.extend_from_slice(&i.to_ne_bytes());
+///     bytes.extend_from_slice(&(i as u32 * 100).to_ne_bytes());
+/// }
+///
+/// let values = StridedSlice::<u32>::new_pedantic(&bytes, 2, 6).unwrap();
+/// assert_eq!(values.len(), 3);
+/// assert_eq!(values.get(1), Some(100));
+/// assert_eq!(values.iter().collect::<Vec<_>>(), vec![0, 100, 200]);
+/// ```
+pub struct StridedSlice<'a, T: PodTransmutable> {
+    bytes: &'a [u8],
+    offset: usize,
+    stride: usize,
+    len: usize,
+    _type: PhantomData<T>,
+}
+
+impl<'a, T: PodTransmutable> StridedSlice<'a, T> {
+    /// View the elements at `offset` in each `stride`-byte record.
+    ///
+    /// The byte slice must have at least enough bytes to fill a single element,
+    /// extraneous data is ignored.
+    ///
+    /// `stride` must be non-zero and fit an element at `offset`, or an `ErrorReason::InvalidStride` error is returned.
+    pub fn new(bytes: &'a [u8], offset: usize, stride: usize) -> Result<StridedSlice<'a, T>, Error> {
+        let ret = StridedSlice::new_permissive(bytes, offset, stride)?;
+        if ret.len == 0 {
+            Err(Error {
+                required: offset + size_of::<T>(),
+                actual: bytes.len(),
+                reason: ErrorReason::NotEnoughBytes,
+            })
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// View the elements at `offset` in each `stride`-byte record.
+    ///
+    /// The resulting view will have as many elements as will fit, rounded down.
+    ///
+    /// `stride` must be non-zero and fit an element at `offset`, or an `ErrorReason::InvalidStride` error is returned.
+    pub fn new_permissive(bytes: &'a [u8], offset: usize, stride: usize) -> Result<StridedSlice<'a, T>, Error> {
+        let end = match offset.checked_add(size_of::<T>()) {
+            Some(end) if end <= stride && stride != 0 => end,
+            end => {
+                return Err(Error {
+                    required: end.map_or(usize::MAX, |end| end.max(1)),
+                    actual: stride,
+                    reason: ErrorReason::InvalidStride,
+                })
+            }
+        };
+
+        Ok(StridedSlice {
+            bytes,
+            offset,
+            stride,
+            len: if bytes.len() < end {
+                0
+            } else {
+                (bytes.len() - end) / stride + 1
+            },
+            _type: PhantomData,
+        })
+    }
+
+    /// View the elements at `offset` in each `stride`-byte record.
+    ///
+    /// The byte slice must consist of at least one whole record, and should not have extraneous data.
+    ///
+    /// `stride` must be non-zero and fit an element at `offset`, or an `ErrorReason::InvalidStride` error is returned.
+    pub fn new_pedantic(bytes: &'a [u8], offset: usize, stride: usize) -> Result<StridedSlice<'a, T>, Error> {
+        let ret = StridedSlice::new(bytes, offset, stride)?;
+        if bytes.len() % stride != 0 {
+            Err(Error {
+                required: stride,
+                actual: bytes.len(),
+                reason: ErrorReason::InexactByteCount,
+            })
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// Get the amount of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the byte offset of the elements in each record.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the size of each record.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Read the element at the specified index, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<T>
+        where T: Copy
+    {
+        if index < self.len {
+            Some(unsafe { self.get_unchecked(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the elements by value.
+    pub fn iter(&self) -> StridedIter<'a, T>
+        where T: Copy
+    {
+        StridedIter {
+            slice: *self,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> T
+        where T: Copy
+    {
+        ptr::read_unaligned(self.bytes.as_ptr().add(self.offset + index * self.stride) as *const T)
+    }
+}
+
+impl<'a, T: PodTransmutable> Clone for StridedSlice<'a, T> {
+    fn clone(&self) -> StridedSlice<'a, T> {
+        *self
+    }
+}
+
+impl<'a, T: PodTransmutable> Copy for StridedSlice<'a, T> {}
+
+impl<'a, T: PodTransmutable> fmt::Debug for StridedSlice<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StridedSlice")
+            .field("offset", &self.offset)
+            .field("stride", &self.stride)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> IntoIterator for StridedSlice<'a, T> {
+    type Item = T;
+    type IntoIter = StridedIter<'a, T>;
+
+    fn into_iter(self) -> StridedIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> IntoIterator for &StridedSlice<'a, T> {
+    type Item = T;
+    type IntoIter = StridedIter<'a, T>;
+
+    fn into_iter(self) -> StridedIter<'a, T> {
+        self.iter()
+    }
+}
+
+
+/// Iterator over the elements of a `StridedSlice`, by value.
+pub struct StridedIter<'a, T: PodTransmutable> {
+    slice: StridedSlice<'a, T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: PodTransmutable> Clone for StridedIter<'a, T> {
+    fn clone(&self) -> StridedIter<'a, T> {
+        StridedIter { ..*self }
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> Iterator for StridedIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(unsafe { self.slice.get_unchecked(self.front - 1) })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> DoubleEndedIterator for StridedIter<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { self.slice.get_unchecked(self.back) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T: PodTransmutable + Copy> ExactSizeIterator for StridedIter<'a, T> {}
+
+impl<'a, T: PodTransmutable + Copy> FusedIterator for StridedIter<'a, T> {}
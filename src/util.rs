@@ -1,9 +1,6 @@
 //! Module containing various utility functions.
 
 
-use std::mem::transmute;
-
-
 /// If the specified 32-bit float is a signaling NaN, make it a quiet NaN.
 ///
 /// Based on [`f32::from_bits()`](https://github.com/rust-lang/rust/pull/39271/files#diff-f60977ab00fd9ea9ba7ac918e12a8f42R1279)
@@ -12,7 +9,7 @@ pub fn designalise_f32(f: f32) -> f32 {
     const QNAN_MASK: u32 = 0x00400000;
     const FRACT_MASK: u32 = 0x007FFFFF;
 
-    let mut f: u32 = unsafe { transmute(f) };
+    let mut f = f.to_bits();
 
     if f & EXP_MASK == EXP_MASK && f & FRACT_MASK != 0 {
         // If we have a NaN value, we
@@ -21,7 +18,7 @@ pub fn designalise_f32(f: f32) -> f32 {
         f |= QNAN_MASK;
     }
 
-    unsafe { transmute(f) }
+    f32::from_bits(f)
 }
 
 /// If the specified 64-bit float is a signaling NaN, make it a quiet NaN.
@@ -32,7 +29,7 @@ pub fn designalise_f64(f: f64) -> f64 {
     const QNAN_MASK: u64 = 0x0001000000000000;
     const FRACT_MASK: u64 = 0x000FFFFFFFFFFFFF;
 
-    let mut f: u64 = unsafe { transmute(f) };
+    let mut f = f.to_bits();
 
     if f & EXP_MASK == EXP_MASK && f & FRACT_MASK != 0 {
         // If we have a NaN value, we
@@ -41,5 +38,5 @@ pub fn designalise_f64(f: f64) -> f64 {
         f |= QNAN_MASK;
     }
 
-    unsafe { transmute(f) }
+    f64::from_bits(f)
 }
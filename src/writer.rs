@@ -0,0 +1,160 @@
+use std::io::{self, BufWriter, ErrorKind, Write};
+use std::mem::size_of;
+use std::slice;
+use self::super::endian::{primitive_lanes, swap_lanes};
+use self::super::{PodTransmutable, Endianness, guarded_transmute_to_bytes_pod_many};
+
+
+/// Size of the scratch buffer used for byte order conversion.
+const SCRATCH_SIZE: usize = 8 * 1024;
+
+
+/// Writer adapter serialising POD records into a byte stream.
+///
+/// Records are written in native byte order by default; if a different `Endianness` is set,
+/// the bytes of each primitive in the records are reversed, following their types' `layout()`.
+/// Records of types with an `Opaque` layout, other than single bytes, can then only be written in native byte order.
+///
+/// This adapter doesn't buffer its output, wrap the underlying writer in a `BufWriter`
+/// (or create the adapter with `PodWriter::buffered()`) when writing many small records.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Endianness, PodWriter};
+/// let mut writer = PodWriter::new(vec![]);
+/// writer.set_endianness(Endianness::Big);
+///
+/// writer.write_record(&0x0102u16).unwrap();
+/// writer.write_records(&[0x0304u16, 0x0506]).unwrap();
+///
+/// assert_eq!(writer.records_written(), 3);
+/// assert_eq!(writer.into_inner(), vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+/// ```
+pub struct PodWriter<W: Write> {
+    inner: W,
+    endianness: Endianness,
+    scratch: Vec<u8>,
+    records_written: u64,
+    bytes_written: u64,
+}
+
+impl<W: Write> PodWriter<W> {
+    /// Create a writer outputting records in native byte order.
+    pub fn new(inner: W) -> PodWriter<W> {
+        PodWriter {
+            inner,
+            endianness: Endianness::NATIVE,
+            scratch: Vec::new(),
+            records_written: 0,
+            bytes_written: 0,
+        }
+    }
+
+    /// Create a writer outputting records in native byte order through a default-sized `BufWriter`.
+    pub fn buffered(inner: W) -> PodWriter<BufWriter<W>> {
+        PodWriter::new(BufWriter::new(inner))
+    }
+
+    /// Set the byte order records are written in.
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        self.endianness = endianness;
+    }
+
+    /// Get the byte order records are written in.
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Get the amount of records written so far.
+    pub fn records_written(&self) -> u64 {
+        self.records_written
+    }
+
+    /// Get the amount of bytes written so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    /// Write a single record.
+    pub fn write_record<T: PodTransmutable>(&mut self, record: &T) -> io::Result<()> {
+        self.write_records(slice::from_ref(record))
+    }
+
+    /// Write all records in a slice.
+    ///
+    /// Records which can't be converted to a non-native byte order are refused with an `InvalidInput` error.
+    ///
+    /// If the underlying writer fails partway through, the bytes, and whole records, written before the failure are still counted.
+    pub fn write_records<T: PodTransmutable>(&mut self, records: &[T]) -> io::Result<()> {
+        let start = self.bytes_written;
+        let result = self.write_bytes::<T>(guarded_transmute_to_bytes_pod_many(records));
+
+        self.records_written += match size_of::<T>() {
+            0 => records.len() as u64,
+            size => (self.bytes_written - start) / size as u64,
+        };
+        result
+    }
+
+    /// Write the bytes of records of type `T`, converting their byte order if needed.
+    fn write_bytes<T: PodTransmutable>(&mut self, bytes: &[u8]) -> io::Result<()> {
+        if self.endianness.is_native() || size_of::<T>() <= 1 {
+            write_all_counted(&mut self.inner, bytes, &mut self.bytes_written)
+        } else {
+            let mut lanes = Vec::new();
+            if !primitive_lanes(&T::layout(), 0, &mut lanes) {
+                return Err(io::Error::new(ErrorKind::InvalidInput, "Record type has an opaque layout, so its byte order can't be converted"));
+            }
+
+            let chunk_size = (SCRATCH_SIZE / size_of::<T>()).max(1) * size_of::<T>();
+            for chunk in bytes.chunks(chunk_size) {
+                self.scratch.clear();
+                self.scratch.extend_from_slice(chunk);
+                swap_lanes(&mut self.scratch, size_of::<T>(), &lanes);
+                write_all_counted(&mut self.inner, &self.scratch, &mut self.bytes_written)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Flush the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Get a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this writer.
+    ///
+    /// A `BufWriter` returned this way still needs to be flushed, or unwrapped with `BufWriter::into_inner()`.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+
+/// Write all of `buf`, like `Write::write_all()`, adding the amount of bytes written to `written` as it goes,
+/// so it's accurate even if a write fails.
+fn write_all_counted<W: Write>(writer: &mut W, mut buf: &[u8], written: &mut u64) -> io::Result<()> {
+    while !buf.is_empty() {
+        match writer.write(buf) {
+            Ok(0) => return Err(io::Error::new(ErrorKind::WriteZero, "failed to write whole buffer")),
+            Ok(len) => {
+                *written += len as u64;
+                buf = &buf[len..];
+            }
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
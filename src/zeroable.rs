@@ -0,0 +1,128 @@
+use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+#[cfg(feature = "alloc")]
+use alloc::alloc::{Layout, alloc_zeroed, handle_alloc_error};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::mem::size_of;
+use core::mem::zeroed as mem_zeroed;
+use self::super::PodTransmutable;
+
+
+/// Type for which an all-zero bit pattern is a valid instance.
+///
+/// Implemented for all `PodTransmutable` types, `char`, `Option`al non-zero integers, and raw pointers.
+///
+/// Marker trait for the `zeroed*()` functions.
+///
+/// # Safety
+///
+/// An all-zero bit pattern must be a valid instance of the implementing type, so this must not be implemented for
+/// e.g. references, `NonZero*` integers, or `enum`s without a zero discriminant.
+///
+/// # Examples
+///
+/// References aren't `Zeroable`:
+///
+/// ```compile_fail
+/// # use safe_transmute::zeroed;
+/// let r: &u8 = zeroed();
+/// ```
+pub unsafe trait Zeroable {}
+
+unsafe impl<T: PodTransmutable> Zeroable for T {}
+
+unsafe impl Zeroable for char {}
+
+unsafe impl Zeroable for Option<NonZeroU8> {}
+unsafe impl Zeroable for Option<NonZeroI8> {}
+unsafe impl Zeroable for Option<NonZeroU16> {}
+unsafe impl Zeroable for Option<NonZeroI16> {}
+unsafe impl Zeroable for Option<NonZeroU32> {}
+unsafe impl Zeroable for Option<NonZeroI32> {}
+unsafe impl Zeroable for Option<NonZeroU64> {}
+unsafe impl Zeroable for Option<NonZeroI64> {}
+unsafe impl Zeroable for Option<NonZeroU128> {}
+unsafe impl Zeroable for Option<NonZeroI128> {}
+unsafe impl Zeroable for Option<NonZeroUsize> {}
+unsafe impl Zeroable for Option<NonZeroIsize> {}
+unsafe impl<T> Zeroable for *const T {}
+unsafe impl<T> Zeroable for *mut T {}
+
+
+/// Create an instance of a type with all of its bytes set to zero.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::zeroed;
+/// # use std::num::NonZeroU32;
+/// assert_eq!(zeroed::<u32>(), 0);
+/// assert_eq!(zeroed::<Option<NonZeroU32>>(), None);
+/// assert!(zeroed::<*const u8>().is_null());
+/// ```
+pub fn zeroed<T: Zeroable>() -> T {
+    unsafe { mem_zeroed() }
+}
+
+/// Create a vector of `len` instances of a type with all of their bytes set to zero.
+///
+/// The buffer is acquired from a zeroed allocation, so no per-element initialisation is performed,
+/// and the resulting vector's length and capacity are both `len`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::zeroed_vec;
+/// let mut buf = zeroed_vec::<u16>(4);
+/// assert_eq!(buf, vec![0, 0, 0, 0]);
+///
+/// buf[1] = 0x0102;
+/// assert_eq!(buf, vec![0, 0x0102, 0, 0]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn zeroed_vec<T: Zeroable>(len: usize) -> Vec<T> {
+    if len == 0 || size_of::<T>() == 0 {
+        let mut ret = Vec::with_capacity(len);
+        ret.resize_with(len, zeroed);
+        return ret;
+    }
+
+    let layout = Layout::array::<T>(len).expect("capacity overflow");
+    unsafe {
+        let ptr = alloc_zeroed(layout);
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        Vec::from_raw_parts(ptr as *mut T, len, len)
+    }
+}
+
+/// Create a boxed instance of a type with all of its bytes set to zero.
+///
+/// The instance is created directly in a zeroed allocation, so even very large types don't pass through the stack.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::zeroed_box;
+/// let table = zeroed_box::<u64>();
+/// assert_eq!(*table, 0);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn zeroed_box<T: Zeroable>() -> Box<T> {
+    if size_of::<T>() == 0 {
+        return Box::new(zeroed());
+    }
+
+    let layout = Layout::new::<T>();
+    unsafe {
+        let ptr = alloc_zeroed(layout);
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        Box::from_raw(ptr as *mut T)
+    }
+}
@@ -0,0 +1,39 @@
+//! Guarded transmutation of types implementing `zerocopy`'s marker traits.
+//!
+//! Available with the `zerocopy` feature.
+//!
+//! Types from other crates can't implement `PodTransmutable`, so these mirror the `guarded_transmute_pod_*()` functions,
+//! with the same guards and errors, for types which are `zerocopy::FromBytes` (and `Immutable` for views, `IntoBytes` for the reverse) instead.
+//!
+//! Zero-sized types like `()` are `FromBytes` too, but are refused by the slice and vector functions with `ErrorReason::ZeroSizedType`.
+//!
+//! # Examples
+//!
+//! ```
+//! # extern crate safe_transmute;
+//! # extern crate zerocopy;
+//! # use safe_transmute::zerocopy::{guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_many_pedantic};
+//! # use safe_transmute::{ErrorReason, Error};
+//! # fn main() {
+//! let bytes = guarded_transmute_to_bytes_pod_many(&[[1u8, 2, 3], [4, 5, 6]]);
+//! assert_eq!(guarded_transmute_pod_many_pedantic::<[u8; 3]>(bytes), Ok(&[[1, 2, 3], [4, 5, 6]][..]));
+//! assert_eq!(guarded_transmute_pod_many_pedantic::<[u8; 4]>(bytes),
+//!            Err(Error {
+//!                required: 4,
+//!                actual: 6,
+//!                reason: ErrorReason::InexactByteCount,
+//!            }));
+//! # }
+//! ```
+
+
+use zerocopy_crate::{IntoBytes, FromBytes, Immutable};
+
+
+pod_bridge! {
+    value: [FromBytes + Copy],
+    view: [FromBytes + Immutable],
+    vec: [FromBytes],
+    to_bytes: [IntoBytes + Immutable],
+    to_bytes_mut: [IntoBytes + FromBytes]
+}
@@ -0,0 +1,38 @@
+#[repr(C)]
+struct Record {
+    id: u32,
+    kind: u8,
+    flags: [u8; 3],
+    value: f64,
+}
+
+pod_transmutable!(Record { id, kind, flags, value });
+
+// assert_pod_layout!()'s compile_fail example, with the right offsets
+#[repr(C)]
+struct Header {
+    magic: u16,
+    version: u16,
+    len: u32,
+}
+
+pod_transmutable!(Header { magic, version, len });
+
+
+assert_pod_layout!(u32, size = 4);
+assert_pod_layout!([u16; 3], size = 6, align = 2);
+assert_pod_layout!(Record, size = 16, align = 8, fields { id: 0, kind: 4, flags: 5, value: 8 });
+assert_pod_layout!(Record, size = 16, fields { value: 8, });
+assert_pod_layout!(Header, size = 8, fields { magic: 0, len: 4 });
+
+
+#[test]
+#[cfg(feature = "alloc")]
+fn runtime_layout_agrees() {
+    use safe_transmute::{LayoutKind, PodTransmutable};
+
+    match Record::layout().kind {
+        LayoutKind::Struct { fields } => assert_eq!(fields.iter().map(|f| f.offset).collect::<Vec<_>>(), vec![0, 4, 5, 8]),
+        kind => panic!("{:?}", kind),
+    }
+}
@@ -0,0 +1,104 @@
+use safe_transmute::bytemuck::{guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_pod_many_permissive, guarded_transmute_pod_many_pedantic,
+                               guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_pedantic, guarded_transmute_pod_many,
+                               guarded_transmute_pod};
+#[cfg(feature = "alloc")]
+use safe_transmute::bytemuck::guarded_transmute_pod_vec_pedantic;
+use safe_transmute::{ErrorReason, Error};
+use bytemuck::{Zeroable, Pod};
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+const RED: Rgba = Rgba {
+    r: 0xFF,
+    g: 0,
+    b: 0,
+    a: 0xFF,
+};
+const BLUE: Rgba = Rgba {
+    r: 0,
+    g: 0,
+    b: 0xFF,
+    a: 0x80,
+};
+
+
+#[test]
+fn single() {
+    let bytes = [0xFF, 0, 0, 0xFF, 0xED];
+    assert_eq!(guarded_transmute_pod::<Rgba>(&bytes), Ok(RED));
+    assert_eq!(guarded_transmute_pod::<Rgba>(&bytes[..3]),
+               Err(Error {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(guarded_transmute_pod_pedantic::<Rgba>(&bytes),
+               Err(Error {
+                   required: 4,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn many() {
+    let bytes = [0xFF, 0, 0, 0xFF, 0, 0, 0xFF, 0x80, 0xED];
+    assert_eq!(guarded_transmute_pod_many::<Rgba>(&bytes), Ok(&[RED, BLUE][..]));
+    assert_eq!(guarded_transmute_pod_many_permissive::<Rgba>(&bytes[..3]), &[]);
+    assert_eq!(guarded_transmute_pod_many_pedantic::<Rgba>(&bytes),
+               Err(Error {
+                   required: 4,
+                   actual: 9,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<Rgba>(vec![0, 0, 0xFF, 0x80]), Ok(vec![BLUE]));
+}
+
+#[test]
+fn to_bytes() {
+    let mut colours = [RED, BLUE];
+    assert_eq!(guarded_transmute_to_bytes_pod_many(&colours), &[0xFF, 0, 0, 0xFF, 0, 0, 0xFF, 0x80]);
+
+    guarded_transmute_to_bytes_pod_many_mut(&mut colours)[4..].copy_from_slice(&[0xFF, 0, 0, 0xFF]);
+    assert_eq!(colours, [RED, RED]);
+}
+
+#[test]
+fn unaligned() {
+    let values = [1u32, 2, 3];
+    let bytes = guarded_transmute_to_bytes_pod_many(&values);
+    assert_eq!(guarded_transmute_pod_many::<u32>(&bytes[1..9]),
+               Err(Error {
+                   required: 32 / 8,
+                   actual: 1,
+                   reason: ErrorReason::UnalignedMemory,
+               }));
+    assert_eq!(guarded_transmute_pod_many_permissive::<u32>(&bytes[1..9]), &[]);
+    assert_eq!(guarded_transmute_pod::<u32>(&bytes[1..5]), Ok(u32::from_ne_bytes([bytes[1], bytes[2], bytes[3], bytes[4]])));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_many::<()>(&[1]),
+               Err(Error {
+                   required: 0,
+                   actual: 1,
+                   reason: ErrorReason::ZeroSizedType,
+               }));
+    assert_eq!(guarded_transmute_pod_many_pedantic::<()>(&[]).unwrap_err().reason, ErrorReason::ZeroSizedType);
+    assert_eq!(guarded_transmute_pod_many_permissive::<()>(&[1]), &[]);
+    assert_eq!(guarded_transmute_pod::<()>(&[1]), Ok(()));
+}
@@ -0,0 +1,153 @@
+use safe_transmute::container::{self, ContainerError, LayoutField, HEADER_SIZE, Header, layout_fingerprint};
+use safe_transmute::{guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many, DetailedError, Endianness, ErrorReason, Error, zeroed_vec};
+use std::io::ErrorKind;
+
+
+fn container_error(err: ::std::io::Error) -> ContainerError {
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    *err.get_ref().and_then(|e| e.downcast_ref::<ContainerError>()).unwrap()
+}
+
+
+#[test]
+fn header_round_trip() {
+    let header = Header::of::<f64>(12);
+    assert_eq!(header,
+               Header {
+                   version: 1,
+                   endianness: Endianness::NATIVE,
+                   element_size: 8,
+                   element_alignment: 8,
+                   layout_fingerprint: layout_fingerprint::<f64>(),
+                   element_count: 12,
+               });
+    assert_eq!(Header::parse(&header.to_bytes()), Ok(header));
+}
+
+#[test]
+fn invalid_header() {
+    assert_eq!(Header::parse(&[0; HEADER_SIZE]), Err(ContainerError::InvalidMagic));
+    assert_eq!(Header::parse(&Header::of::<u8>(0).to_bytes()[..HEADER_SIZE - 1]), Err(ContainerError::InvalidMagic));
+
+    let mut bytes = Header::of::<u8>(0).to_bytes();
+    bytes[8] = 2;
+    assert_eq!(Header::parse(&bytes), Err(ContainerError::UnsupportedVersion(2)));
+}
+
+#[test]
+fn read_write() {
+    let values: Vec<i16> = (-100..100).collect();
+    let mut file = vec![];
+    container::write(&mut file, &values).unwrap();
+    assert_eq!(file.len(), HEADER_SIZE + 200 * 2);
+
+    assert_eq!(container::read::<i16>(&mut &file[..]).unwrap(), values);
+}
+
+#[test]
+fn read_layout_mismatch() {
+    let mut file = vec![];
+    container::write(&mut file, &[1u32, 2]).unwrap();
+
+    assert_eq!(container_error(container::read::<u64>(&mut &file[..]).unwrap_err()),
+               ContainerError::LayoutMismatch {
+                   field: LayoutField::Size,
+                   expected: 8,
+                   found: 4,
+               });
+    assert_eq!(container_error(container::read::<f32>(&mut &file[..]).unwrap_err()),
+               ContainerError::LayoutMismatch {
+                   field: LayoutField::Fingerprint,
+                   expected: layout_fingerprint::<f32>(),
+                   found: layout_fingerprint::<u32>(),
+               });
+
+    file[10] ^= 1;
+    let err = container_error(container::read::<u32>(&mut &file[..]).unwrap_err());
+    assert_eq!(err,
+               ContainerError::LayoutMismatch {
+                   field: LayoutField::Endianness,
+                   expected: Endianness::NATIVE as u64,
+                   found: Endianness::NATIVE as u64 ^ 1,
+               });
+    assert_eq!(err.to_string(),
+               format!("Element Endianness mismatch (expected: {}, found: {})", Endianness::NATIVE as u64, Endianness::NATIVE as u64 ^ 1));
+}
+
+#[test]
+fn read_truncated() {
+    let mut file = vec![];
+    container::write(&mut file, &[1u32, 2]).unwrap();
+    file.pop();
+
+    let err = container::read::<u32>(&mut &file[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(err.get_ref().and_then(|e| e.downcast_ref::<DetailedError>()).map(|e| e.error),
+               Some(Error {
+                   required: 2 * 32 / 8,
+                   actual: 7,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn read_huge_count() {
+    let mut file = Header { element_count: 1 << 40, ..Header::of::<u64>(0) }.to_bytes().to_vec();
+    file.extend_from_slice(guarded_transmute_to_bytes_pod_many(&[1u64, 2]));
+
+    let err = container::read::<u64>(&mut &file[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(err.get_ref().and_then(|e| e.downcast_ref::<DetailedError>()).map(|e| e.error),
+               Some(Error {
+                   required: (1 << 40) * 64 / 8,
+                   actual: 2 * 64 / 8,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+
+    let file = Header { element_count: u64::MAX, ..Header::of::<u64>(0) }.to_bytes();
+    assert_eq!(container::read::<u64>(&mut &file[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn read_zero_sized() {
+    let file = Header { element_count: 1 << 40, ..Header::of::<[u8; 0]>(0) }.to_bytes();
+
+    let err = container::read::<[u8; 0]>(&mut &file[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(Error::from_io_error(&err),
+               Some(Error {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedType,
+               }));
+}
+
+#[test]
+fn view() {
+    let mut buf = zeroed_vec::<u32>(HEADER_SIZE / 4 + 3);
+    container::write(&mut guarded_transmute_to_bytes_pod_many_mut(&mut buf), &[7u32, 8, 9]).unwrap();
+    let bytes = guarded_transmute_to_bytes_pod_many(&buf);
+
+    let (header, values) = container::view::<u32>(bytes).unwrap();
+    assert_eq!(header.element_count, 3);
+    assert_eq!(values, &[7, 8, 9]);
+
+    assert_eq!(container::view::<u32>(&bytes[..bytes.len() - 4]),
+               Err(ContainerError::CountMismatch {
+                   expected: 3,
+                   found: 2,
+               }));
+    assert_eq!(container::view::<u32>(&bytes[..bytes.len() - 1]),
+               Err(ContainerError::Transmute(Error {
+                   required: 32 / 8,
+                   actual: 11,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn view_empty() {
+    let mut file = vec![];
+    container::write::<u16>(&mut file, &[]).unwrap();
+    assert_eq!(container::view::<u16>(&file).unwrap().1, &[]);
+}
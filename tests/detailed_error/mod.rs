@@ -0,0 +1,78 @@
+use safe_transmute::{DetailedError, ErrorContext, ErrorReason, Error, guarded_transmute_pod_many_pedantic, guarded_transmute_pod_many_max,
+                     guarded_transmute_pod_pedantic};
+
+
+const ERROR: Error = Error {
+    required: 8,
+    actual: 3,
+    reason: ErrorReason::InexactByteCount,
+};
+
+const ERROR_U16: Error = Error {
+    required: 2,
+    actual: 3,
+    reason: ErrorReason::InexactByteCount,
+};
+
+
+#[test]
+fn context() {
+    let err = ERROR.with_context::<u64>(&[0x01, 0x02, 0x03]);
+    assert_eq!(err.error, ERROR);
+    assert_eq!(err.reason, ErrorReason::InexactByteCount);
+    assert_eq!(err.type_name, "u64");
+    assert_eq!((err.offset, err.index), (None, None));
+    assert_eq!(err.preview(), &[0x01, 0x02, 0x03]);
+    assert_eq!(Error::from(err), ERROR);
+
+    let err = err.at_offset(24).at_index(3);
+    assert_eq!((err.offset, err.index), (Some(24), Some(3)));
+}
+
+#[test]
+fn display() {
+    assert_eq!(DetailedError::new::<u64>(ERROR, &[]).to_string(),
+               "Not exactly the amount of bytes for type `u64` (required: 8, actual: 3)");
+    assert_eq!(DetailedError::new::<u64>(ERROR, &[0x0A, 0xFF]).at_index(7).to_string(),
+               "Not exactly the amount of bytes for type `u64` (required: 8, actual: 3) at element 7: 0a ff");
+    assert_eq!(DetailedError::new::<[u16; 2]>(ERROR, &[0xFF]).at_offset(12).at_index(3).to_string(),
+               "Not exactly the amount of bytes for type `[u16; 2]` (required: 8, actual: 3) at byte offset 12, element 3: ff");
+}
+
+#[test]
+fn long_preview() {
+    let bytes: Vec<u8> = (0..40).collect();
+    let err = guarded_transmute_pod_pedantic::<u32>(&bytes).unwrap_err().with_context::<u32>(&bytes);
+    assert_eq!(err.preview(), &bytes[..DetailedError::PREVIEW_LEN]);
+    assert!(err.to_string().ends_with(": 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f ..."));
+}
+
+#[test]
+fn inferred_context() {
+    let bytes = [0x01, 0x02, 0x03];
+    assert_eq!(guarded_transmute_pod_pedantic::<u16>(&bytes[..2]).with_context(&bytes[..2]), Ok(u16::from_ne_bytes([0x01, 0x02])));
+
+    let err = guarded_transmute_pod_pedantic::<[u16; 2]>(&bytes).with_context(&bytes).unwrap_err();
+    assert_eq!(err.type_name, "[u16; 2]");
+    assert_eq!(err.preview(), &bytes);
+
+    let err = guarded_transmute_pod_many_pedantic::<u16>(&bytes).with_context(&bytes).unwrap_err();
+    assert_eq!(err, ERROR_U16.with_context::<u16>(&bytes[2..]).at_offset(2).at_index(1));
+}
+
+#[test]
+fn inferred_location() {
+    let bytes: Vec<u8> = (0..11).collect();
+
+    let err = guarded_transmute_pod_pedantic::<u32>(&bytes).with_context(&bytes).unwrap_err();
+    assert_eq!((err.offset, err.index, err.preview()), (Some(4), None, &bytes[4..]));
+
+    let err = guarded_transmute_pod_many_pedantic::<u32>(&bytes).with_context(&bytes).unwrap_err();
+    assert_eq!((err.offset, err.index, err.preview()), (Some(8), Some(2), &bytes[8..]));
+
+    let err = guarded_transmute_pod_many_max::<u16>(&bytes, 3).with_context(&bytes).unwrap_err();
+    assert_eq!((err.reason, err.offset, err.index, err.preview()), (ErrorReason::TooManyBytes, Some(6), Some(3), &bytes[6..]));
+
+    let err = guarded_transmute_pod_many_pedantic::<u32>(&bytes[..3]).with_context(&bytes[..3]).unwrap_err();
+    assert_eq!((err.reason, err.offset, err.index, err.preview()), (ErrorReason::NotEnoughBytes, None, None, &bytes[..3]));
+}
@@ -0,0 +1,69 @@
+use safe_transmute::{ErrorReason, Error, guarded_transmute_pod_many_pedantic, guarded_transmute_pod_vec_pedantic, guarded_transmute_pod, read_pod};
+use std::io::{self, ErrorKind};
+use std::error::Error as StdError;
+
+
+fn parse(bytes: &[u8]) -> io::Result<u32> {
+    Ok(guarded_transmute_pod::<u32>(bytes)?)
+}
+
+
+#[test]
+fn io_error_kind() {
+    let err = parse(&[0x00, 0x01]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(err.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+               Some(&Error {
+                   required: 32 / 8,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+
+    let err = io::Error::from(guarded_transmute_pod_many_pedantic::<u16>(&[0x00, 0x01, 0x02]).unwrap_err());
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let err = io::Error::from(guarded_transmute_pod_many_pedantic::<[u16; 0]>(&[0x00, 0x01]).unwrap_err());
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    let err = io::Error::from(guarded_transmute_pod::<u16>(&[]).unwrap_err().with_context::<u16>(&[]).at_offset(6));
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert!(err.to_string().contains("at byte offset 6"));
+}
+
+#[test]
+fn display() {
+    let err = Error {
+        required: 2,
+        actual: 1,
+        reason: ErrorReason::UnalignedMemory,
+    };
+    assert_eq!(err.to_string(), "Bytes not aligned for type (required: 2, actual: 1)");
+    assert_eq!(ErrorReason::TooManyBytes.to_string(), "Too many bytes for type");
+}
+
+#[test]
+fn source() {
+    let err = guarded_transmute_pod::<u64>(&[0x00]).unwrap_err();
+    assert!(err.source().is_none());
+
+    let vec_err = guarded_transmute_pod_vec_pedantic::<u64>(vec![0x00]).unwrap_err();
+    assert_eq!(vec_err.to_string(), err.to_string());
+    assert!(vec_err.source().is_none());
+
+    let detailed = err.with_context::<u64>(&[0x00]);
+    assert!(detailed.to_string().starts_with("Not enough bytes to fill type `u64` (required: 8, actual: 1)"));
+    assert!(detailed.source().is_none());
+}
+
+#[test]
+fn from_io_error() {
+    let err = guarded_transmute_pod::<u32>(&[0x00]).unwrap_err();
+    assert_eq!(Error::from_io_error(&io::Error::from(err)), Some(err));
+    assert_eq!(Error::from_io_error(&io::Error::from(err.with_context::<u32>(&[0x00]))), Some(err));
+    assert_eq!(Error::from_io_error(&io::Error::new(ErrorKind::InvalidData, guarded_transmute_pod_vec_pedantic::<u32>(vec![0x00]).unwrap_err())),
+               Some(err));
+    assert_eq!(Error::from_io_error(&read_pod::<u32>(&mut &[0x00][..]).unwrap_err()), Some(err));
+
+    assert_eq!(Error::from_io_error(&io::Error::other("unrelated")), None);
+    assert_eq!(Error::from_io_error(&io::Error::from(ErrorKind::Other)), None);
+}
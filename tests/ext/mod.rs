@@ -0,0 +1,80 @@
+use safe_transmute::{ErrorReason, Error, PodSliceExt, PodFromBytes, PodAsBytes, guarded_transmute_pod_many_pedantic, guarded_transmute_pod_pedantic};
+#[cfg(feature = "alloc")]
+use safe_transmute::{IntoPodVec, guarded_transmute_pod_vec_pedantic};
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Header {
+    magic: u16,
+    len: u16,
+}
+
+pod_transmutable!(Header { magic, len });
+
+
+#[test]
+fn as_pod_slice() {
+    let values = [1u16, 2, 3];
+    let bytes = values.as_bytes();
+    assert_eq!(bytes.as_pod_slice::<u16>(), Ok(&values[..]));
+    assert_eq!(bytes[..5].as_pod_slice::<u16>(), guarded_transmute_pod_many_pedantic::<u16>(&bytes[..5]));
+    assert_eq!([0x00u8].as_pod_slice::<u16>(),
+               Err(Error {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn as_pod_slice_unaligned() {
+    let values = [1u16, 2, 3];
+    assert_eq!(values.as_bytes()[1..5].as_pod_slice::<u16>(),
+               Err(Error {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::UnalignedMemory,
+               }));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn as_pod_slice_owned() {
+    let values = [1u32, 2];
+    let vec = values.as_bytes().to_vec();
+    assert_eq!(vec.as_pod_slice::<u32>().map(|s| s.len()), guarded_transmute_pod_many_pedantic::<u32>(&vec).map(|s| s.len()));
+    let boxed = vec.clone().into_boxed_slice();
+    assert_eq!(boxed.as_pod_slice::<u32>().map(|s| s.len()), guarded_transmute_pod_many_pedantic::<u32>(&boxed).map(|s| s.len()));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn into_pod_vec() {
+    let values = [0u8; 8];
+    assert_eq!(values.into_pod_vec::<u32>().map_err(Error::from),
+               guarded_transmute_pod_vec_pedantic::<u32>(values.to_vec()).map_err(Error::from));
+    assert_eq!(values.to_vec().into_pod_vec::<u16>().map_err(Error::from), Ok(vec![0; 4]));
+    assert_eq!(values.to_vec().into_boxed_slice().into_pod_vec::<u64>().map_err(Error::from), Ok(vec![0]));
+
+    let err = vec![0u8; 3].into_pod_vec::<u16>().unwrap_err();
+    assert_eq!(err.reason, ErrorReason::InexactByteCount);
+    assert_eq!(err.into_inner(), vec![0; 3]);
+}
+
+#[test]
+fn as_bytes() {
+    let mut values = [0x0102u16, 0x0304];
+    assert_eq!(values.as_bytes(), &[0x0102u16.to_ne_bytes(), 0x0304u16.to_ne_bytes()].concat()[..]);
+    values.as_bytes_mut()[..2].copy_from_slice(&0x0506u16.to_ne_bytes());
+    assert_eq!(values, [0x0506, 0x0304]);
+}
+
+#[test]
+fn from_bytes() {
+    let header = [Header { magic: 0xCAFE, len: 4 }];
+    let bytes = header.as_bytes();
+    assert_eq!(Header::from_bytes(bytes), Ok(header[0]));
+    assert_eq!(Header::from_bytes(&bytes[..3]), guarded_transmute_pod_pedantic::<Header>(&bytes[..3]));
+    assert_eq!(u8::from_bytes(&[0x00, 0x01]).unwrap_err().reason, ErrorReason::InexactByteCount);
+}
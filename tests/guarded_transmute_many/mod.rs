@@ -24,9 +24,9 @@ fn too_short() {
 fn just_enough() {
     unsafe {
         assert_eq!(guarded_transmute_many::<u16>(&[0x00, 0x01].le_to_native::<u16>()),
-                   Ok([0x0100u16].into_iter().as_slice()));
+                   Ok([0x0100u16].iter().as_slice()));
         assert_eq!(guarded_transmute_many::<u16>(&[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>()),
-                   Ok([0x0100u16, 0x0200u16].into_iter().as_slice()));
+                   Ok([0x0100u16, 0x0200u16].iter().as_slice()));
     }
 }
 
@@ -34,10 +34,10 @@ fn just_enough() {
 fn too_much() {
     unsafe {
         assert_eq!(guarded_transmute_many::<u16>(&[0x00, 0x01, 0x00].le_to_native::<u16>()),
-                   Ok([0x0100u16].into_iter().as_slice()));
+                   Ok([0x0100u16].iter().as_slice()));
         assert_eq!(guarded_transmute_many::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00].le_to_native::<u16>()),
-                   Ok([0x0100u16, 0x0200u16].into_iter().as_slice()));
+                   Ok([0x0100u16, 0x0200u16].iter().as_slice()));
         assert_eq!(guarded_transmute_many::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00].le_to_native::<u16>()),
-                   Ok([0x0100u16, 0x0200u16, 0x0300u16].into_iter().as_slice()));
+                   Ok([0x0100u16, 0x0200u16, 0x0300u16].iter().as_slice()));
     }
 }
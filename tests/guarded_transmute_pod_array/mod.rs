@@ -0,0 +1,75 @@
+use safe_transmute::{ErrorReason, Error, guarded_transmute_pod_array_owned_pedantic, guarded_transmute_pod_array_mut_pedantic,
+                     guarded_transmute_pod_array_pedantic, guarded_transmute_pod_array_owned, guarded_transmute_pod_array_mut,
+                     guarded_transmute_pod_array, guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many};
+
+
+#[test]
+fn too_short() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2]);
+    let err = Error {
+        required: 3 * (16 / 8),
+        actual: 4,
+        reason: ErrorReason::NotEnoughBytes,
+    };
+    assert_eq!(guarded_transmute_pod_array::<u16, 3>(bytes), Err(err));
+    assert_eq!(guarded_transmute_pod_array_pedantic::<u16, 3>(bytes), Err(err));
+    assert_eq!(guarded_transmute_pod_array_owned::<u16, 3>(bytes), Err(err));
+    assert_eq!(guarded_transmute_pod_array_owned_pedantic::<u16, 3>(bytes), Err(err));
+}
+
+#[test]
+fn just_enough() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2, 3]);
+    assert_eq!(guarded_transmute_pod_array::<u16, 3>(bytes), Ok(&[1, 2, 3]));
+    assert_eq!(guarded_transmute_pod_array_pedantic::<u16, 3>(bytes), Ok(&[1, 2, 3]));
+    assert_eq!(guarded_transmute_pod_array_owned::<u16, 3>(bytes), Ok([1, 2, 3]));
+    assert_eq!(guarded_transmute_pod_array_owned_pedantic::<u16, 3>(bytes), Ok([1, 2, 3]));
+}
+
+#[test]
+fn too_much() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2, 3]);
+    assert_eq!(guarded_transmute_pod_array::<u16, 2>(bytes), Ok(&[1, 2]));
+    assert_eq!(guarded_transmute_pod_array::<u16, 2>(&bytes[..5]), Ok(&[1, 2]));
+    assert_eq!(guarded_transmute_pod_array_owned::<u16, 2>(bytes), Ok([1, 2]));
+
+    let err = Error {
+        required: 2 * (16 / 8),
+        actual: 5,
+        reason: ErrorReason::InexactByteCount,
+    };
+    assert_eq!(guarded_transmute_pod_array_pedantic::<u16, 2>(&bytes[..5]), Err(err));
+    assert_eq!(guarded_transmute_pod_array_owned_pedantic::<u16, 2>(&bytes[..5]), Err(err));
+}
+
+#[test]
+fn unaligned() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u16, 2, 3]);
+    assert_eq!(guarded_transmute_pod_array::<u16, 2>(&bytes[1..]),
+               Err(Error {
+                   required: 2,
+                   actual: 1,
+                   reason: ErrorReason::UnalignedMemory,
+               }));
+    assert_eq!(guarded_transmute_pod_array_owned::<u16, 2>(&bytes[1..]).map(|a| a.len()), Ok(2));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_array::<u32, 0>(&[0x00]), Ok(&[]));
+    assert_eq!(guarded_transmute_pod_array_pedantic::<u32, 0>(&[]), Ok(&[]));
+    assert_eq!(guarded_transmute_pod_array_mut_pedantic::<u32, 0>(&mut []), Ok(&mut []));
+}
+
+#[test]
+fn mutable() {
+    let mut data = [0u32; 4];
+    {
+        let bytes = guarded_transmute_to_bytes_pod_many_mut(&mut data);
+        guarded_transmute_pod_array_mut::<u32, 2>(&mut bytes[4..]).unwrap()[1] = 5;
+        assert_eq!(guarded_transmute_pod_array_mut_pedantic::<u32, 3>(&mut bytes[4..]), Ok(&mut [0, 5, 0]));
+        assert_eq!(guarded_transmute_pod_array_mut_pedantic::<u32, 2>(&mut bytes[4..]).unwrap_err().reason,
+                   ErrorReason::InexactByteCount);
+    }
+    assert_eq!(data, [0, 0, 5, 0]);
+}
@@ -1,4 +1,4 @@
-use safe_transmute::{ErrorReason, Error, guarded_transmute_pod_many};
+use safe_transmute::{ErrorReason, Error, guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_many};
 use self::super::LeToNative;
 
 
@@ -21,17 +21,39 @@ fn too_short() {
 #[test]
 fn just_enough() {
     assert_eq!(guarded_transmute_pod_many::<u16>(&[0x00, 0x01].le_to_native::<u16>()),
-               Ok([0x0100u16].into_iter().as_slice()));
+               Ok([0x0100u16].iter().as_slice()));
     assert_eq!(guarded_transmute_pod_many::<u16>(&[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>()),
-               Ok([0x0100u16, 0x0200u16].into_iter().as_slice()));
+               Ok([0x0100u16, 0x0200u16].iter().as_slice()));
 }
 
 #[test]
 fn too_much() {
     assert_eq!(guarded_transmute_pod_many::<u16>(&[0x00, 0x01, 0x00].le_to_native::<u16>()),
-               Ok([0x0100u16].into_iter().as_slice()));
+               Ok([0x0100u16].iter().as_slice()));
     assert_eq!(guarded_transmute_pod_many::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00].le_to_native::<u16>()),
-               Ok([0x0100u16, 0x0200u16].into_iter().as_slice()));
+               Ok([0x0100u16, 0x0200u16].iter().as_slice()));
     assert_eq!(guarded_transmute_pod_many::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00].le_to_native::<u16>()),
-               Ok([0x0100u16, 0x0200u16, 0x0300u16].into_iter().as_slice()));
+               Ok([0x0100u16, 0x0200u16, 0x0300u16].iter().as_slice()));
+}
+
+#[test]
+fn unaligned() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[0u16; 3]);
+    assert_eq!(guarded_transmute_pod_many::<u16>(&bytes[1..]),
+               Err(Error {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::UnalignedMemory,
+               }));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_many::<[u8; 0]>(&[0x01, 0x02]),
+               Err(Error {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::ZeroSizedType,
+               }));
+    assert_eq!(guarded_transmute_pod_many::<[u64; 0]>(&[]).unwrap_err().reason, ErrorReason::ZeroSizedType);
 }
@@ -0,0 +1,55 @@
+use safe_transmute::{ErrorReason, Error, guarded_transmute_pod_many_exact};
+use self::super::LeToNative;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(guarded_transmute_pod_many_exact::<u16>(&[], 2),
+               Err(Error {
+                   required: 2 * (16 / 8),
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(guarded_transmute_pod_many_exact::<u16>(&[0x00, 0x01, 0x00], 2),
+               Err(Error {
+                   required: 2 * (16 / 8),
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn just_enough() {
+    assert_eq!(guarded_transmute_pod_many_exact::<u16>(&[], 0), Ok([].iter().as_slice()));
+    assert_eq!(guarded_transmute_pod_many_exact::<u16>(&[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>(), 2),
+               Ok([0x0100u16, 0x0200u16].iter().as_slice()));
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(guarded_transmute_pod_many_exact::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00], 2),
+               Err(Error {
+                   required: 2 * (16 / 8),
+                   actual: 5,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(guarded_transmute_pod_many_exact::<u16>(&[0x00, 0x01], 0),
+               Err(Error {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_many_exact::<[u32; 0]>(&[], 3), Ok(&[[]; 3][..]));
+    let bytes = [0u8; 2];
+    assert_eq!(guarded_transmute_pod_many_exact::<[u64; 0]>(&bytes[1..1], 2), Ok(&[[]; 2][..]));
+    assert_eq!(guarded_transmute_pod_many_exact::<[u8; 0]>(&[0x01], 3),
+               Err(Error {
+                   required: 0,
+                   actual: 1,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
@@ -0,0 +1,55 @@
+use safe_transmute::{ErrorReason, Error, guarded_transmute_pod_many_max};
+use self::super::LeToNative;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(guarded_transmute_pod_many_max::<u16>(&[], 2),
+               Err(Error {
+                   required: 16 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(guarded_transmute_pod_many_max::<u16>(&[0x00], 2),
+               Err(Error {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn within_limit() {
+    assert_eq!(guarded_transmute_pod_many_max::<u16>(&[0x00, 0x01].le_to_native::<u16>(), 2),
+               Ok([0x0100u16].iter().as_slice()));
+    assert_eq!(guarded_transmute_pod_many_max::<u16>(&[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>(), 2),
+               Ok([0x0100u16, 0x0200u16].iter().as_slice()));
+    assert_eq!(guarded_transmute_pod_many_max::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00].le_to_native::<u16>(), 2),
+               Ok([0x0100u16, 0x0200u16].iter().as_slice()));
+}
+
+#[test]
+fn over_limit() {
+    assert_eq!(guarded_transmute_pod_many_max::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00, 0x03].le_to_native::<u16>(), 2),
+               Err(Error {
+                   required: 2 * (16 / 8),
+                   actual: 6,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(guarded_transmute_pod_many_max::<u16>(&[0x00, 0x01].le_to_native::<u16>(), 0),
+               Err(Error {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_many_max::<[u8; 0]>(&[0x01, 0x02], 3),
+               Err(Error {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::ZeroSizedType,
+               }));
+}
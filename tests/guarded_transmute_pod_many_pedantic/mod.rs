@@ -21,9 +21,9 @@ fn too_short() {
 #[test]
 fn just_enough() {
     assert_eq!(guarded_transmute_pod_many_pedantic::<u16>(&[0x00, 0x01].le_to_native::<u16>()),
-               Ok([0x0100u16].into_iter().as_slice()));
+               Ok([0x0100u16].iter().as_slice()));
     assert_eq!(guarded_transmute_pod_many_pedantic::<u16>(&[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>()),
-               Ok([0x0100u16, 0x0200u16].into_iter().as_slice()));
+               Ok([0x0100u16, 0x0200u16].iter().as_slice()));
 }
 
 #[test]
@@ -41,3 +41,14 @@ fn too_much() {
                    reason: ErrorReason::InexactByteCount,
                }));
 }
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_many_pedantic::<[u8; 0]>(&[0x01, 0x02]),
+               Err(Error {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::ZeroSizedType,
+               }));
+    assert_eq!(guarded_transmute_pod_many_pedantic::<[u8; 0]>(&[]).unwrap_err().reason, ErrorReason::ZeroSizedType);
+}
@@ -1,4 +1,4 @@
-use safe_transmute::guarded_transmute_pod_many_permissive;
+use safe_transmute::{guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_many_permissive};
 use self::super::LeToNative;
 
 
@@ -24,3 +24,15 @@ fn too_much() {
     assert_eq!(guarded_transmute_pod_many_permissive::<u16>(&[0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00].le_to_native::<u16>()),
                &[0x0100u16, 0x0200u16, 0x0300u16]);
 }
+
+#[test]
+fn unaligned() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[0u16; 3]);
+    assert_eq!(guarded_transmute_pod_many_permissive::<u16>(&bytes[1..]), &[]);
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_many_permissive::<[u8; 0]>(&[0x01, 0x02]), &[] as &[[u8; 0]]);
+    assert_eq!(guarded_transmute_pod_many_permissive::<[u8; 0]>(&[]), &[] as &[[u8; 0]]);
+}
@@ -0,0 +1,79 @@
+use safe_transmute::{ErrorReason, Error, guarded_transmute_pod_ref_mut_pedantic, guarded_transmute_pod_ref_pedantic, guarded_transmute_pod_ref_mut,
+                     guarded_transmute_pod_ref, guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many};
+
+
+#[derive(Debug, PartialEq)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    table: [u16; 1024],
+}
+
+pod_transmutable!(Header { magic, table });
+
+
+#[test]
+fn too_short() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u16]);
+    let err = Error {
+        required: 32 / 8,
+        actual: 2,
+        reason: ErrorReason::NotEnoughBytes,
+    };
+    assert_eq!(guarded_transmute_pod_ref::<u32>(bytes), Err(err));
+    assert_eq!(guarded_transmute_pod_ref_pedantic::<u32>(bytes), Err(err));
+}
+
+#[test]
+fn just_enough() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[0x01020304u32]);
+    assert_eq!(guarded_transmute_pod_ref::<u32>(bytes), Ok(&0x01020304));
+    assert_eq!(guarded_transmute_pod_ref_pedantic::<u32>(bytes), Ok(&0x01020304));
+}
+
+#[test]
+fn too_much() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u32, 2]);
+    assert_eq!(guarded_transmute_pod_ref::<u32>(&bytes[..5]), Ok(&1));
+    assert_eq!(guarded_transmute_pod_ref_pedantic::<u32>(&bytes[..5]),
+               Err(Error {
+                   required: 32 / 8,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn unaligned() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u32, 2]);
+    assert_eq!(guarded_transmute_pod_ref::<u32>(&bytes[2..]),
+               Err(Error {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::UnalignedMemory,
+               }));
+}
+
+#[test]
+fn large_struct() {
+    let mut data = [0u32; 513];
+    {
+        let bytes = guarded_transmute_to_bytes_pod_many_mut(&mut data);
+        let header = guarded_transmute_pod_ref_mut_pedantic::<Header>(bytes).unwrap();
+        header.magic = 0xCAFE;
+        header.table[1023] = 7;
+    }
+
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    let header = guarded_transmute_pod_ref::<Header>(bytes).unwrap();
+    assert_eq!(header.magic, 0xCAFE);
+    assert_eq!(header.table[1023], 7);
+    assert_eq!(header.table[..1023].iter().sum::<u16>(), 0);
+}
+
+#[test]
+fn mutable() {
+    let mut data = [0u16; 3];
+    *guarded_transmute_pod_ref_mut::<u16>(&mut guarded_transmute_to_bytes_pod_many_mut(&mut data)[4..]).unwrap() = 9;
+    assert_eq!(data, [0, 0, 9]);
+}
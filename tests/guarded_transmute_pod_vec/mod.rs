@@ -4,13 +4,13 @@ use self::super::LeToNative;
 
 #[test]
 fn too_short() {
-    assert_eq!(guarded_transmute_pod_vec::<u16>(vec![]),
+    assert_eq!(guarded_transmute_pod_vec::<u16>(vec![]).map_err(Error::from),
                Err(Error {
                    required: 16 / 8,
                    actual: 0,
                    reason: ErrorReason::NotEnoughBytes,
                }));
-    assert_eq!(guarded_transmute_pod_vec::<u16>(vec![0x00]),
+    assert_eq!(guarded_transmute_pod_vec::<u16>(vec![0x00]).map_err(Error::from),
                Err(Error {
                    required: 16 / 8,
                    actual: 1,
@@ -34,3 +34,13 @@ fn too_much() {
     assert_eq!(guarded_transmute_pod_vec::<u16>(vec![0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00].le_to_native::<u16>()),
                Ok(vec![0x0100u16, 0x0200u16, 0x0300u16]));
 }
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_vec::<[u8; 0]>(vec![0x01, 0x02]).map_err(Error::from),
+               Err(Error {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::ZeroSizedType,
+               }));
+}
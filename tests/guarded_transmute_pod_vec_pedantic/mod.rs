@@ -4,13 +4,13 @@ use self::super::LeToNative;
 
 #[test]
 fn too_short() {
-    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![]),
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![]).map_err(Error::from),
                Err(Error {
                    required: 16 / 8,
                    actual: 0,
                    reason: ErrorReason::NotEnoughBytes,
                }));
-    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![0x00]),
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![0x00]).map_err(Error::from),
                Err(Error {
                    required: 16 / 8,
                    actual: 1,
@@ -28,16 +28,37 @@ fn just_enough() {
 
 #[test]
 fn too_much() {
-    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00]),
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00]).map_err(Error::from),
                Err(Error {
                    required: 16 / 8,
                    actual: 3,
                    reason: ErrorReason::InexactByteCount,
                }));
-    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00, 0x02, 0x00]),
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00, 0x02, 0x00]).map_err(Error::from),
                Err(Error {
                    required: 16 / 8,
                    actual: 5,
                    reason: ErrorReason::InexactByteCount,
                }));
 }
+
+#[test]
+fn recover() {
+    let err = guarded_transmute_pod_vec_pedantic::<u32>(vec![0x01, 0x02, 0x03]).unwrap_err();
+    assert_eq!((err.required, err.actual, err.reason), (32 / 8, 3, ErrorReason::NotEnoughBytes));
+
+    let mut bytes = err.into_inner();
+    assert_eq!(bytes, vec![0x01, 0x02, 0x03]);
+    bytes.push(0x04);
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<u32>(bytes).map(|v| v.len()), Ok(1));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<[u8; 0]>(vec![]).map_err(Error::from),
+               Err(Error {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedType,
+               }));
+}
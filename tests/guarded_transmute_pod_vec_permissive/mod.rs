@@ -25,3 +25,40 @@ fn too_much() {
     assert_eq!(guarded_transmute_pod_vec_permissive::<u16>(vec![0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00].le_to_native::<u16>()),
                vec![0x0100u16, 0x0200u16, 0x0300u16]);
 }
+
+#[test]
+fn spare_capacity() {
+    let mut bytes = Vec::with_capacity(16);
+    bytes.extend_from_slice(&[0x01, 0x02, 0x03]);
+    let values = guarded_transmute_pod_vec_permissive::<u8>(bytes);
+    assert_eq!(values, vec![0x01, 0x02, 0x03]);
+    assert!(values.capacity() >= 16);
+}
+
+#[test]
+fn reused_allocation() {
+    let bytes = vec![0x01, 0x02, 0x03, 0x04];
+    let ptr = bytes.as_ptr();
+    let values = guarded_transmute_pod_vec_permissive::<[u8; 2]>(bytes);
+    assert_eq!(values, vec![[0x01, 0x02], [0x03, 0x04]]);
+    assert_eq!(values.as_ptr() as *const u8, ptr);
+}
+
+#[test]
+fn mismatched_layout() {
+    let mut bytes = Vec::with_capacity(7);
+    bytes.extend_from_slice(&0x01020304u32.to_ne_bytes());
+    bytes.push(0xFF);
+    let values = guarded_transmute_pod_vec_permissive::<u32>(bytes);
+    assert_eq!(values, vec![0x01020304]);
+    assert_eq!(values.as_ptr() as usize % 4, 0);
+    drop(values);
+
+    let values = guarded_transmute_pod_vec_permissive::<u16>(vec![0x00; 6]);
+    assert_eq!(values, vec![0; 3]);
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(guarded_transmute_pod_vec_permissive::<[u8; 0]>(vec![0x01, 0x02]), Vec::<[u8; 0]>::new());
+}
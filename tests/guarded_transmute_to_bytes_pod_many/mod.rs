@@ -0,0 +1,20 @@
+use safe_transmute::{guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_many_pedantic};
+use self::super::LeToNative;
+
+
+#[test]
+fn empty() {
+    assert_eq!(guarded_transmute_to_bytes_pod_many::<u32>(&[]), &[]);
+}
+
+#[test]
+fn values() {
+    assert_eq!(guarded_transmute_to_bytes_pod_many(&[0x0100u16, 0x0200u16]),
+               &[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>());
+}
+
+#[test]
+fn round_trip() {
+    let values = [1.5f64, -2.0, 1e10];
+    assert_eq!(guarded_transmute_pod_many_pedantic::<f64>(guarded_transmute_to_bytes_pod_many(&values)), Ok(&values[..]));
+}
@@ -0,0 +1,15 @@
+use safe_transmute::guarded_transmute_to_bytes_pod_many_mut;
+use self::super::LeToNative;
+
+
+#[test]
+fn empty() {
+    assert_eq!(guarded_transmute_to_bytes_pod_many_mut::<u32>(&mut []), &[]);
+}
+
+#[test]
+fn fill() {
+    let mut values = [0u16; 2];
+    guarded_transmute_to_bytes_pod_many_mut(&mut values).copy_from_slice(&[0x00, 0x01, 0x00, 0x02].le_to_native::<u16>());
+    assert_eq!(values, [0x0100, 0x0200]);
+}
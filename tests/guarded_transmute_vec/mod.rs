@@ -5,13 +5,13 @@ use self::super::LeToNative;
 #[test]
 fn too_short() {
     unsafe {
-        assert_eq!(guarded_transmute_vec::<u16>(vec![]),
+        assert_eq!(guarded_transmute_vec::<u16>(vec![]).map_err(Error::from),
                    Err(Error {
                        required: 16 / 8,
                        actual: 0,
                        reason: ErrorReason::NotEnoughBytes,
                    }));
-        assert_eq!(guarded_transmute_vec::<u16>(vec![0x00]),
+        assert_eq!(guarded_transmute_vec::<u16>(vec![0x00]).map_err(Error::from),
                    Err(Error {
                        required: 16 / 8,
                        actual: 1,
@@ -5,13 +5,13 @@ use self::super::LeToNative;
 #[test]
 fn too_short() {
     unsafe {
-        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![]),
+        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![]).map_err(Error::from),
                    Err(Error {
                        required: 16 / 8,
                        actual: 0,
                        reason: ErrorReason::NotEnoughBytes,
                    }));
-        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![0x00]),
+        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![0x00]).map_err(Error::from),
                    Err(Error {
                        required: 16 / 8,
                        actual: 1,
@@ -33,13 +33,13 @@ fn just_enough() {
 #[test]
 fn too_much() {
     unsafe {
-        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00]),
+        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00]).map_err(Error::from),
                    Err(Error {
                        required: 16 / 8,
                        actual: 3,
                        reason: ErrorReason::InexactByteCount,
                    }));
-        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00, 0x02, 0x00]),
+        assert_eq!(guarded_transmute_vec_pedantic::<u16>(vec![0x00, 0x01, 0x00, 0x02, 0x00]).map_err(Error::from),
                    Err(Error {
                        required: 16 / 8,
                        actual: 5,
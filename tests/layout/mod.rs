@@ -0,0 +1,122 @@
+use safe_transmute::{FieldLayout, LayoutKind, TypeLayout, PodTransmutable, guarded_transmute_pod};
+#[cfg(feature = "std")]
+use safe_transmute::container::layout_fingerprint;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+pod_transmutable!(Point { x, y });
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct Vertex {
+    position: Point,
+    color: [u8; 4],
+    index: u32,
+}
+
+pod_transmutable!(Vertex { position, color, index });
+
+#[cfg(feature = "std")]
+#[repr(C)]
+struct Renamed {
+    x: f32,
+    y: f32,
+}
+
+#[cfg(feature = "std")]
+pod_transmutable!(Renamed { x, y });
+
+#[cfg(feature = "std")]
+#[repr(C)]
+struct Swapped {
+    y: f32,
+    x: f32,
+}
+
+#[cfg(feature = "std")]
+pod_transmutable!(Swapped { y, x });
+
+#[repr(C)]
+struct Opaque(u64);
+
+unsafe impl PodTransmutable for Opaque {}
+
+
+#[test]
+fn primitive() {
+    assert_eq!(u32::layout(),
+               TypeLayout {
+                   name: "u32",
+                   size: 4,
+                   align: 4,
+                   kind: LayoutKind::Primitive,
+               });
+    assert_eq!(f64::layout().kind, LayoutKind::Primitive);
+}
+
+#[test]
+fn array() {
+    let layout = <[[u16; 2]; 3]>::layout();
+    assert_eq!(layout.size, 12);
+    assert_eq!(layout.align, 2);
+    assert_eq!(layout.kind,
+               LayoutKind::Array {
+                   element: Box::new(<[u16; 2]>::layout()),
+                   len: 3,
+               });
+}
+
+#[test]
+fn structure() {
+    let layout = Vertex::layout();
+    assert_eq!(layout.size, 16);
+    assert_eq!(layout.align, 4);
+    assert_eq!(layout.kind,
+               LayoutKind::Struct {
+                   fields: vec![FieldLayout {
+                                    name: "position",
+                                    offset: 0,
+                                    layout: Point::layout(),
+                                },
+                                FieldLayout {
+                                    name: "color",
+                                    offset: 8,
+                                    layout: <[u8; 4]>::layout(),
+                                },
+                                FieldLayout {
+                                    name: "index",
+                                    offset: 12,
+                                    layout: u32::layout(),
+                                }],
+               });
+}
+
+#[test]
+fn opaque() {
+    let layout = Opaque::layout();
+    assert!(layout.name.ends_with("Opaque"));
+    assert_eq!(layout.size, 8);
+    assert_eq!(layout.kind, LayoutKind::Opaque);
+}
+
+#[test]
+fn transmute() {
+    let bytes = [1.5f32.to_ne_bytes(), (-2.0f32).to_ne_bytes()].concat();
+    assert_eq!(guarded_transmute_pod::<Point>(&bytes), Ok(Point { x: 1.5, y: -2.0 }));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn fingerprint() {
+    assert_eq!(layout_fingerprint::<Point>(), layout_fingerprint::<Renamed>());
+    assert_ne!(layout_fingerprint::<Point>(), layout_fingerprint::<Swapped>());
+    assert_ne!(layout_fingerprint::<Point>(), layout_fingerprint::<[f32; 2]>());
+    assert_ne!(layout_fingerprint::<[u8; 4]>(), layout_fingerprint::<[i8; 4]>());
+    assert_ne!(layout_fingerprint::<Opaque>(), layout_fingerprint::<u64>());
+}
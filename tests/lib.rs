@@ -1,4 +1,15 @@
+#[macro_use]
 extern crate safe_transmute;
+#[cfg(feature = "mmap")]
+extern crate tempfile;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy;
 
 
 mod util;
@@ -7,17 +18,69 @@ mod guarded_transmute_many;
 mod guarded_transmute_pedantic;
 mod guarded_transmute_many_pedantic;
 mod guarded_transmute_many_permissive;
+#[cfg(feature = "alloc")]
 mod guarded_transmute_vec;
+#[cfg(feature = "alloc")]
 mod guarded_transmute_vec_pedantic;
+#[cfg(feature = "alloc")]
 mod guarded_transmute_vec_permissive;
 mod guarded_transmute_pod;
 mod guarded_transmute_pod_many;
 mod guarded_transmute_pod_pedantic;
 mod guarded_transmute_pod_many_pedantic;
 mod guarded_transmute_pod_many_permissive;
+mod guarded_transmute_pod_many_max;
+mod guarded_transmute_pod_many_exact;
+mod guarded_transmute_pod_array;
+mod guarded_transmute_pod_ref;
+mod pod_align_to;
+mod pod_iter;
+mod ext;
+#[cfg(feature = "alloc")]
 mod guarded_transmute_pod_vec;
+#[cfg(feature = "alloc")]
 mod guarded_transmute_pod_vec_pedantic;
+#[cfg(feature = "alloc")]
 mod guarded_transmute_pod_vec_permissive;
+mod zeroed;
+#[cfg(feature = "alloc")]
+mod zeroed_vec;
+#[cfg(feature = "alloc")]
+mod zeroed_box;
+#[cfg(feature = "std")]
+mod pod_reader;
+#[cfg(feature = "std")]
+mod pod_writer;
+mod guarded_transmute_to_bytes_pod_many;
+mod guarded_transmute_to_bytes_pod_many_mut;
+#[cfg(feature = "std")]
+mod read_pod;
+#[cfg(feature = "std")]
+mod read_pod_into;
+#[cfg(feature = "std")]
+mod npy;
+#[cfg(feature = "std")]
+mod container;
+#[cfg(feature = "alloc")]
+mod layout;
+mod assert_pod_layout;
+mod packed;
+mod strided_slice;
+mod pod_matrix;
+mod pod_array_view;
+mod detailed_error;
+#[cfg(feature = "std")]
+mod error_conversion;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "ndarray")]
+mod ndarray_conversions;
+#[cfg(feature = "bytes")]
+mod pod_bytes;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_bridge;
+#[cfg(feature = "zerocopy")]
+mod zerocopy_bridge;
 
 
 include!("test_util/le_to_native.rs");
@@ -0,0 +1,83 @@
+use safe_transmute::mmap::{MappedPodSliceMut, MappedPodSlice};
+use safe_transmute::{ErrorReason, Error};
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::fs::File;
+use tempfile;
+
+
+fn file_with(values: &[u32]) -> File {
+    let mut file = tempfile::tempfile().unwrap();
+    for v in values {
+        file.write_all(&v.to_ne_bytes()).unwrap();
+    }
+    file
+}
+
+fn transmute_error(err: io::Error) -> Error {
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    *err.get_ref().and_then(|e| e.downcast_ref::<Error>()).unwrap()
+}
+
+
+#[test]
+fn whole() {
+    let file = file_with(&[1, 2, 3]);
+    let values = unsafe { MappedPodSlice::<u32>::map(&file) }.unwrap();
+    assert_eq!(&values[..], &[1, 2, 3]);
+}
+
+#[test]
+fn window() {
+    let file = file_with(&[1, 2, 3, 4]);
+    let values = unsafe { MappedPodSlice::<u32>::map_window(&file, 4, 8) }.unwrap();
+    assert_eq!(&values[..], &[2, 3]);
+}
+
+#[test]
+fn empty() {
+    let file = file_with(&[]);
+    assert_eq!(transmute_error(unsafe { MappedPodSlice::<u32>::map(&file) }.err().unwrap()),
+               Error {
+                   required: 32 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+}
+
+#[test]
+fn inexact() {
+    let file = file_with(&[1, 2]);
+    assert_eq!(transmute_error(unsafe { MappedPodSlice::<u32>::map_window(&file, 0, 6) }.err().unwrap()),
+               Error {
+                   required: 32 / 8,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               });
+}
+
+#[test]
+fn unaligned() {
+    let file = file_with(&[1, 2, 3]);
+    assert_eq!(transmute_error(unsafe { MappedPodSlice::<u32>::map_window(&file, 2, 8) }.err().unwrap()),
+               Error {
+                   required: 32 / 8,
+                   actual: 2,
+                   reason: ErrorReason::UnalignedMemory,
+               });
+}
+
+#[test]
+fn write_through() {
+    let mut file = file_with(&[1, 2, 3]);
+    {
+        let mut values = unsafe { MappedPodSliceMut::<u32>::map_window(&file, 4, 8) }.unwrap();
+        assert_eq!(&values[..], &[2, 3]);
+        values[1] = 0x01020304;
+        values.flush().unwrap();
+    }
+
+    let mut bytes = vec![];
+    file.seek(SeekFrom::Start(0)).unwrap();
+    file.read_to_end(&mut bytes).unwrap();
+    assert_eq!(bytes, [1u32.to_ne_bytes(), 2u32.to_ne_bytes(), 0x01020304u32.to_ne_bytes()].concat());
+}
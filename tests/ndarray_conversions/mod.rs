@@ -0,0 +1,79 @@
+use safe_transmute::{ErrorReason, Error, guarded_transmute_to_bytes_pod_many};
+use ndarray::{Array3, Ix2, arr1, arr2, Array};
+use safe_transmute::ndarray::{into_array, view};
+
+
+#[test]
+fn view_matrix() {
+    let data = [1i16, 2, 3, 4, 5, 6];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(view::<i16, _>(bytes, (3, 2)).unwrap(), arr2(&[[1, 2], [3, 4], [5, 6]]));
+    assert_eq!(view::<i16, _>(bytes, Ix2(2, 3)).unwrap(), arr2(&[[1, 2, 3], [4, 5, 6]]));
+    assert_eq!(view::<i16, _>(bytes, 6).unwrap().to_vec(), data.to_vec());
+}
+
+#[test]
+fn wrong_size() {
+    let data = [0u32; 5];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(view::<u32, _>(bytes, (2, 3)).unwrap_err(),
+               Error {
+                   required: 24,
+                   actual: 20,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    let err = into_array::<u32, _>(bytes.to_vec(), [2, 2]).unwrap_err();
+    assert_eq!(err.error,
+               Error {
+                   required: 16,
+                   actual: 20,
+                   reason: ErrorReason::InexactByteCount,
+               });
+    assert_eq!(err.into_inner(), bytes);
+}
+
+#[test]
+fn unaligned() {
+    let data = [0u64; 2];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(view::<u32, _>(&bytes[1..9], (1, 2)).unwrap_err(),
+               Error {
+                   required: 4,
+                   actual: 1,
+                   reason: ErrorReason::UnalignedMemory,
+               });
+}
+
+#[test]
+fn owned_odd_capacity() {
+    let data = [0x01020304u32, 0x05060708];
+    let mut bytes = Vec::with_capacity(9);
+    bytes.extend_from_slice(guarded_transmute_to_bytes_pod_many(&data));
+    assert_eq!(into_array::<u32, _>(bytes, 2).unwrap(), arr1(&data));
+}
+
+#[test]
+fn owned() {
+    let data: Vec<f64> = (0..24).map(f64::from).collect();
+    let array = into_array::<f64, _>(guarded_transmute_to_bytes_pod_many(&data).to_vec(), (2, 3, 4)).unwrap();
+    assert_eq!(array, Array3::from_shape_vec((2, 3, 4), data).unwrap());
+    assert_eq!(array[[1, 2, 3]], 23.0);
+}
+
+#[test]
+fn empty() {
+    assert_eq!(view::<u64, _>(&[], (0, 4)).unwrap().shape(), &[0, 4]);
+    assert_eq!(into_array::<u64, _>(vec![], [3, 0]).unwrap(), Array::<u64, _>::zeros((3, 0)));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(view::<[u32; 0], _>(&[], (2, 3)).unwrap_err(),
+               Error {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedType,
+               });
+    assert_eq!(Error::from(into_array::<[u32; 0], _>(vec![], [4]).unwrap_err()).reason, ErrorReason::ZeroSizedType);
+    assert_eq!(view::<[u32; 0], _>(&[], (2, 0)).unwrap().shape(), &[2, 0]);
+}
@@ -0,0 +1,162 @@
+use safe_transmute::npy::{self, NpyError, Header, Dtype};
+use safe_transmute::{Endianness, guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many, zeroed_vec};
+
+
+/// Build an `.npy` file with the specified header dictionary and data.
+fn file(major_version: u8, dict: &str, data: &[u8]) -> Vec<u8> {
+    let mut ret = b"\x93NUMPY".to_vec();
+    ret.extend_from_slice(&[major_version, 0]);
+    if major_version == 1 {
+        ret.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    } else {
+        ret.extend_from_slice(&(dict.len() as u32).to_le_bytes());
+    }
+    ret.extend_from_slice(dict.as_bytes());
+    ret.extend_from_slice(data);
+    ret
+}
+
+/// Copy the bytes into a buffer aligned for all supported types.
+fn aligned(bytes: &[u8]) -> Vec<u64> {
+    let mut buf = zeroed_vec::<u64>(bytes.len().div_ceil(8));
+    guarded_transmute_to_bytes_pod_many_mut(&mut buf)[..bytes.len()].copy_from_slice(bytes);
+    buf
+}
+
+
+#[test]
+fn parse_header() {
+    let bytes = file(1, "{'descr': '<i2', 'fortran_order': True, 'shape': (2, 3), }   \n", &[]);
+    assert_eq!(Header::parse(&bytes),
+               Ok(Header {
+                   version: (1, 0),
+                   dtype: Dtype {
+                       endianness: Some(Endianness::Little),
+                       kind: 'i',
+                       size: 2,
+                   },
+                   fortran_order: true,
+                   shape: vec![2, 3],
+                   data_offset: bytes.len(),
+               }));
+
+    let bytes = file(2, "{\"shape\": (5L,), \"fortran_order\": False, \"descr\": \"|u1\"}\n", &[]);
+    let header = Header::parse(&bytes).unwrap();
+    assert_eq!(header.version, (2, 0));
+    assert_eq!(header.dtype.endianness, None);
+    assert_eq!(header.shape, vec![5]);
+
+    let bytes = file(1, "{'descr': '<f8', 'fortran_order': False, 'shape': ()}", &[]);
+    assert_eq!(Header::parse(&bytes).unwrap().element_count(), 1);
+}
+
+#[test]
+fn invalid_header() {
+    assert_eq!(Header::parse(b"NUMPY"), Err(NpyError::InvalidHeader("missing magic string")));
+    assert_eq!(Header::parse(&file(4, "{}", &[])), Err(NpyError::UnsupportedVersion(4, 0)));
+    assert_eq!(Header::parse(&file(1, "{'descr': '<u4', 'shape': (1,)}", &[])),
+               Err(NpyError::InvalidHeader("missing fortran_order")));
+    assert_eq!(Header::parse(&file(1, "{'descr': '<U4', 'fortran_order': False, 'shape': (1,), 'extra': 1}", &[])),
+               Err(NpyError::InvalidHeader("unknown header key")));
+    assert_eq!(Header::parse(&file(1, "{'descr': 'O', 'fortran_order': False, 'shape': (1,)}", &[])),
+               Err(NpyError::UnsupportedDtype("O".to_string())));
+
+    let mut truncated = file(1, "{'descr': '<u4', 'fortran_order': False, 'shape': (1,)}", &[]);
+    truncated.pop();
+    assert_eq!(Header::parse(&truncated), Err(NpyError::InvalidHeader("truncated header")));
+}
+
+#[test]
+fn huge_shape() {
+    let bytes = file(1, "{'descr': '<u8', 'fortran_order': False, 'shape': (4294967296, 4294967296, 2), }", &[0x00; 8]);
+    assert_eq!(Header::parse(&bytes), Err(NpyError::InvalidHeader("shape too large")));
+    assert_eq!(npy::decode::<u64>(&bytes).map(|_| ()), Err(NpyError::InvalidHeader("shape too large")));
+
+    let header = Header { shape: vec![usize::MAX, 2], ..Header::parse(&file(1, "{'descr': '<u8', 'fortran_order': False, 'shape': ()}", &[])).unwrap() };
+    assert_eq!(header.element_count(), usize::MAX);
+
+    let err = npy::write(&mut vec![], &[1u8, 2], &[usize::MAX, 2]).unwrap_err();
+    assert_eq!(err.get_ref().and_then(|e| e.downcast_ref::<NpyError>()),
+               Some(&NpyError::ShapeMismatch {
+                   expected: usize::MAX,
+                   found: 2,
+               }));
+}
+
+#[test]
+fn write_round_trip() {
+    let values: Vec<i32> = (-6..6).collect();
+    let mut bytes = vec![];
+    npy::write(&mut bytes, &values, &[3, 2, 2]).unwrap();
+
+    let header = Header::parse(&bytes).unwrap();
+    assert_eq!(header.data_offset % 64, 0);
+    assert_eq!(header.dtype, Dtype::of::<i32>());
+    assert_eq!(header.shape, vec![3, 2, 2]);
+    assert_eq!(bytes[header.data_offset - 1], b'\n');
+
+    assert_eq!(npy::decode::<i32>(&bytes).unwrap().1, values);
+
+    let buf = aligned(&bytes);
+    assert_eq!(npy::view::<i32>(&guarded_transmute_to_bytes_pod_many(&buf)[..bytes.len()]).unwrap().1,
+               &values[..]);
+}
+
+#[test]
+fn write_shape_mismatch() {
+    let err = npy::write(&mut vec![], &[1u8, 2, 3], &[2, 2]).unwrap_err();
+    assert_eq!(err.get_ref().and_then(|e| e.downcast_ref::<NpyError>()),
+               Some(&NpyError::ShapeMismatch {
+                   expected: 4,
+                   found: 3,
+               }));
+}
+
+#[test]
+fn decode_swapped() {
+    let bytes = file(1, "{'descr': '>u2', 'fortran_order': False, 'shape': (2,), }", &[0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(npy::decode::<u16>(&bytes).unwrap().1, vec![0x0102, 0x0304]);
+
+    let bytes = file(1, "{'descr': '<u2', 'fortran_order': False, 'shape': (2,), }", &[0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(npy::decode::<u16>(&bytes).unwrap().1, vec![0x0201, 0x0403]);
+}
+
+#[test]
+fn view_non_native() {
+    let descr = if cfg!(target_endian = "little") { ">u2" } else { "<u2" };
+    let bytes = file(1, &format!("{{'descr': '{}', 'fortran_order': False, 'shape': (2,), }}", descr), &[0; 4]);
+    let buf = aligned(&bytes);
+    assert_eq!(npy::view::<u16>(&guarded_transmute_to_bytes_pod_many(&buf)[..bytes.len()]),
+               Err(NpyError::NonNativeEndianness(Dtype::parse(descr).unwrap())));
+}
+
+#[test]
+fn dtype_mismatch() {
+    let mut bytes = vec![];
+    npy::write(&mut bytes, &[1.0f32], &[1]).unwrap();
+    assert_eq!(npy::decode::<u32>(&bytes),
+               Err(NpyError::DtypeMismatch {
+                   expected: Dtype::of::<u32>(),
+                   found: Dtype::of::<f32>(),
+               }));
+}
+
+#[test]
+fn count_mismatch() {
+    let bytes = file(1, "{'descr': '|u1', 'fortran_order': False, 'shape': (2, 2), }", &[0; 3]);
+    assert_eq!(npy::decode::<u8>(&bytes),
+               Err(NpyError::ShapeMismatch {
+                   expected: 4,
+                   found: 3,
+               }));
+}
+
+#[test]
+fn empty() {
+    let mut bytes = vec![];
+    npy::write::<f64>(&mut bytes, &[], &[0, 3]).unwrap();
+    let (header, values) = npy::decode::<f64>(&bytes).unwrap();
+    assert_eq!(header.shape, vec![0, 3]);
+    assert_eq!(values, vec![]);
+    assert_eq!(npy::view::<f64>(&bytes).unwrap().1, &[]);
+}
@@ -0,0 +1,83 @@
+use safe_transmute::{guarded_transmute_pod_many_pedantic, guarded_transmute_pod_many, guarded_transmute_pod};
+#[cfg(feature = "alloc")]
+use safe_transmute::{LayoutKind, PodTransmutable};
+
+
+#[derive(Clone, Copy)]
+#[repr(C, packed)]
+struct Record {
+    kind: u8,
+    id: u32,
+    value: f64,
+    tail: [u16; 2],
+}
+
+pod_transmutable!(packed Record {
+    pub kind: u8,
+    pub id: u32,
+    value: f64,
+    tail: [u16; 2],
+});
+
+assert_pod_layout!(Record, size = 17, align = 1, fields { kind: 0, id: 1, value: 5, tail: 13 });
+
+
+fn record_bytes(kind: u8, id: u32, value: f64, tail: [u16; 2]) -> Vec<u8> {
+    let mut ret = vec![kind];
+    ret.extend_from_slice(&id.to_ne_bytes());
+    ret.extend_from_slice(&value.to_ne_bytes());
+    ret.extend_from_slice(&tail[0].to_ne_bytes());
+    ret.extend_from_slice(&tail[1].to_ne_bytes());
+    ret
+}
+
+
+#[test]
+#[cfg(feature = "alloc")]
+fn layout() {
+    match Record::layout().kind {
+        LayoutKind::Struct { fields } => assert_eq!(fields.iter().map(|f| f.offset).collect::<Vec<_>>(), vec![0, 1, 5, 13]),
+        kind => panic!("{:?}", kind),
+    }
+}
+
+#[test]
+fn single() {
+    let bytes = record_bytes(3, 0x01020304, 1.5, [7, 8]);
+    let record = guarded_transmute_pod::<Record>(&bytes).unwrap();
+    assert_eq!(record.kind(), 3);
+    assert_eq!(record.id(), 0x01020304);
+    assert_eq!(record.value(), 1.5);
+    assert_eq!(record.tail(), [7, 8]);
+}
+
+#[test]
+fn many_at_every_offset() {
+    let mut bytes = vec![];
+    for i in 0..4 {
+        bytes.extend(record_bytes(i as u8, i * 10, i as f64 / 2.0, [i as u16, 0xFFFF]));
+    }
+
+    for offset in 0..8 {
+        let mut shifted = vec![0xAA; offset];
+        shifted.extend_from_slice(&bytes);
+
+        let records = guarded_transmute_pod_many_pedantic::<Record>(&shifted[offset..]).unwrap();
+        assert_eq!(records.len(), 4);
+        for (i, record) in records.iter().enumerate() {
+            assert_eq!(record.kind(), i as u8);
+            assert_eq!(record.id(), i as u32 * 10);
+            assert_eq!(record.value(), i as f64 / 2.0);
+            assert_eq!(record.tail(), [i as u16, 0xFFFF]);
+        }
+
+        assert_eq!(guarded_transmute_pod_many::<Record>(&shifted[offset..shifted.len() - 1]).unwrap().len(), 3);
+    }
+}
+
+#[test]
+fn assign() {
+    let mut record = guarded_transmute_pod::<Record>(&record_bytes(0, 0, 0.0, [0, 0])).unwrap();
+    record.id = 42;
+    assert_eq!(record.id(), 42);
+}
@@ -0,0 +1,49 @@
+use safe_transmute::{pod_align_to_mut, pod_align_to, guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many};
+
+
+#[test]
+fn aligned() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u32, 2, 3]);
+    assert_eq!(pod_align_to::<u32>(bytes), (&[][..], &[1, 2, 3][..], &[][..]));
+    assert_eq!(pod_align_to::<u32>(&bytes[..10]), (&[][..], &[1, 2][..], &bytes[8..10]));
+}
+
+#[test]
+fn every_offset() {
+    let data = [0x01020304u32; 8];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    for offset in 0..8 {
+        let (head, body, tail) = pod_align_to::<u32>(&bytes[offset..]);
+        assert_eq!(head, &bytes[offset..offset + head.len()]);
+        assert_eq!((offset + head.len()) % 4, 0);
+        assert!(tail.len() < 4);
+        assert_eq!(head.len() + body.len() * 4 + tail.len(), bytes.len() - offset);
+        assert!(body.iter().all(|&e| e == 0x01020304));
+    }
+}
+
+#[test]
+fn too_short() {
+    let bytes = guarded_transmute_to_bytes_pod_many(&[1u32]);
+    let (head, body, tail) = pod_align_to::<u32>(&bytes[1..]);
+    assert_eq!(head.len() + tail.len(), 3);
+    assert!(body.is_empty());
+}
+
+#[test]
+fn zero_sized() {
+    let bytes = [1u8, 2, 3];
+    assert_eq!(pod_align_to::<[u32; 0]>(&bytes), (&bytes[..], &[][..], &[][..]));
+}
+
+#[test]
+fn mutable() {
+    let mut data = [0u16; 4];
+    {
+        let bytes = &mut guarded_transmute_to_bytes_pod_many_mut(&mut data)[1..];
+        let (head, body, tail) = pod_align_to_mut::<u16>(bytes);
+        assert_eq!((head.len(), body.len(), tail.len()), (1, 3, 0));
+        body[2] = 7;
+    }
+    assert_eq!(data[3], 7);
+}
@@ -0,0 +1,96 @@
+use safe_transmute::{ErrorReason, PodArrayView, Error, guarded_transmute_to_bytes_pod_many};
+
+
+#[test]
+fn wrong_size() {
+    let data = [0u16; 7];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(PodArrayView::<u16, 2>::new(bytes, [2, 4]).unwrap_err(),
+               Error {
+                   required: 16,
+                   actual: 14,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!(PodArrayView::<u16, 3>::new(bytes, [1, 2, 3]).unwrap_err(),
+               Error {
+                   required: 12,
+                   actual: 14,
+                   reason: ErrorReason::InexactByteCount,
+               });
+}
+
+#[test]
+fn unaligned() {
+    let data = [0u64; 2];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(PodArrayView::<u32, 1>::new(&bytes[2..10], [2]).unwrap_err(),
+               Error {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::UnalignedMemory,
+               });
+}
+
+#[test]
+fn index() {
+    let data: Vec<i32> = (0..2 * 3 * 4 * 5).collect();
+    let array = PodArrayView::<i32, 4>::new(guarded_transmute_to_bytes_pod_many(&data), [2, 3, 4, 5]).unwrap();
+    assert_eq!(array.shape(), [2, 3, 4, 5]);
+    assert_eq!(array.len(), 120);
+    assert_eq!(array.as_slice(), &data[..]);
+
+    assert_eq!(array.get([0, 0, 0, 0]), Some(&0));
+    assert_eq!(array.get([1, 2, 3, 4]), Some(&119));
+    assert_eq!(array[[1, 0, 2, 1]], 60 + 10 + 1);
+    assert_eq!(array.get([0, 3, 0, 0]), None);
+    assert_eq!(array.get([0, 0, 0, 5]), None);
+}
+
+#[test]
+fn rows() {
+    let data: Vec<u8> = (0..12).collect();
+    let array = PodArrayView::<u8, 3>::new(&data, [2, 2, 3]).unwrap();
+    assert_eq!(array.rows().collect::<Vec<_>>(),
+               vec![&[0, 1, 2][..], &[3, 4, 5][..], &[6, 7, 8][..], &[9, 10, 11][..]]);
+    assert_eq!(array.rows().next_back(), Some(&[9, 10, 11][..]));
+}
+
+#[test]
+fn scalar() {
+    let array = PodArrayView::<u8, 0>::new(&[7], []).unwrap();
+    assert_eq!(array.len(), 1);
+    assert_eq!(array[[]], 7);
+    assert_eq!(array.rows().collect::<Vec<_>>(), vec![&[7][..]]);
+}
+
+#[test]
+fn empty() {
+    let array = PodArrayView::<u32, 2>::new(&[], [3, 0]).unwrap();
+    assert!(array.is_empty());
+    assert_eq!(array.get([0, 0]), None);
+    assert_eq!(array.rows().len(), 3);
+}
+
+#[test]
+fn zero_sized() {
+    let array = PodArrayView::<[u32; 0], 1>::new(&[], [3]).unwrap();
+    assert_eq!(array.as_slice(), &[[]; 3][..]);
+    assert_eq!(array.get([2]), Some(&[]));
+    assert_eq!(array.rows().collect::<Vec<_>>(), vec![&[[]; 3][..]]);
+
+    let data = [0u8; 3];
+    let array = PodArrayView::<[u64; 0], 2>::new(&data[1..1], [2, 2]).unwrap();
+    assert_eq!(array.len(), 4);
+}
+
+#[test]
+fn huge() {
+    assert_eq!(PodArrayView::<u16, 2>::new(&[], [1 << (usize::BITS - 1), 2]).unwrap_err(),
+               Error {
+                   required: usize::MAX,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!(PodArrayView::<u16, 2>::new(&[], [usize::MAX, 1]).unwrap_err().required, usize::MAX);
+    assert_eq!(PodArrayView::<u16, 3>::new(&[], [usize::MAX, 2, 0]).unwrap_err().required, usize::MAX);
+}
@@ -0,0 +1,84 @@
+use safe_transmute::{ErrorReason, Error, guarded_transmute_to_bytes_pod_many};
+use safe_transmute::bytes::{PodBytes, view_mut};
+use bytes::{BytesMut, Bytes};
+
+
+/// Bytes of the specified `u64`s, in a buffer aligned for them.
+fn aligned(data: &[u64]) -> Bytes {
+    aligned_mut(data).freeze()
+}
+
+/// Bytes of the specified `u64`s, in a mutable buffer aligned for them.
+///
+/// The allocation is overallocated by an alignment's worth, and its unaligned head split off.
+fn aligned_mut(data: &[u64]) -> BytesMut {
+    let data = guarded_transmute_to_bytes_pod_many(data);
+    let mut bytes = BytesMut::zeroed(data.len() + 8);
+    let head = bytes.as_ptr().align_offset(8);
+    let mut bytes = bytes.split_off(head);
+    bytes.truncate(data.len());
+    bytes.copy_from_slice(data);
+    bytes
+}
+
+
+#[test]
+fn just_enough() {
+    let values = PodBytes::<u64>::new(aligned(&[1, 2, 3])).unwrap();
+    assert_eq!(&values[..], &[1, 2, 3]);
+    assert_eq!(values.as_bytes().len(), 24);
+
+    let copy = values.clone();
+    drop(values);
+    assert_eq!(&copy[..], &[1, 2, 3]);
+    assert_eq!(copy.into_inner(), aligned(&[1, 2, 3]));
+}
+
+#[test]
+fn sliced() {
+    let bytes = aligned(&[1, 2, 3, 4]);
+    let values = PodBytes::<u32>::new(bytes.slice(8..24)).unwrap();
+    assert_eq!(values.len(), 4);
+    assert_eq!(PodBytes::<u64>::new(bytes.slice(4..20)).unwrap_err(),
+               Error {
+                   required: 8,
+                   actual: 4,
+                   reason: ErrorReason::UnalignedMemory,
+               });
+}
+
+#[test]
+fn wrong_size() {
+    assert_eq!(PodBytes::<u64>::new(aligned(&[1]).slice(..4)).unwrap_err(),
+               Error {
+                   required: 8,
+                   actual: 4,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!(PodBytes::<u64>::new(aligned(&[1, 2]).slice(..12)).unwrap_err(),
+               Error {
+                   required: 8,
+                   actual: 12,
+                   reason: ErrorReason::InexactByteCount,
+               });
+}
+
+#[test]
+fn mutable() {
+    let mut bytes = aligned_mut(&[0, 0]);
+
+    {
+        let values = view_mut::<u32>(&mut bytes).unwrap();
+        assert_eq!(values.len(), 4);
+        values[3] = 0xDEADBEEF;
+    }
+    assert_eq!(&bytes[12..], &0xDEADBEEFu32.to_ne_bytes());
+
+    bytes.truncate(6);
+    assert_eq!(view_mut::<u32>(&mut bytes).unwrap_err(),
+               Error {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               });
+}
@@ -0,0 +1,82 @@
+use safe_transmute::{ErrorReason, Error, pod_iter_permissive, pod_iter_pedantic, pod_iter};
+
+
+fn bytes(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_ne_bytes().to_vec()).collect()
+}
+
+
+#[test]
+fn too_short() {
+    assert_eq!(pod_iter::<u32>(&[0x00, 0x01, 0x02]).unwrap_err(),
+               Error {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!(pod_iter_pedantic::<u32>(&[]).unwrap_err().reason, ErrorReason::NotEnoughBytes);
+
+    let values = pod_iter_permissive::<u32>(&[0x00, 0x01, 0x02]);
+    assert_eq!(values.len(), 0);
+    assert_eq!(values.remainder(), &[0x00, 0x01, 0x02]);
+}
+
+#[test]
+fn unaligned() {
+    let mut data = vec![0xAA];
+    data.extend(bytes(&[1, 2, 3]));
+    data.push(0xBB);
+
+    let values = pod_iter::<u32>(&data[1..]).unwrap();
+    assert_eq!(values.remainder(), &[0xBB]);
+    assert_eq!(values.collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(pod_iter_pedantic::<u32>(&data[1..]).unwrap_err(),
+               Error {
+                   required: 32 / 8,
+                   actual: 13,
+                   reason: ErrorReason::InexactByteCount,
+               });
+    assert_eq!(pod_iter_pedantic::<u32>(&data[1..13]).unwrap().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn double_ended() {
+    let data = bytes(&[1, 2, 3, 4]);
+    let mut values = pod_iter::<u32>(&data).unwrap();
+    assert_eq!(values.len(), 4);
+    assert_eq!(values.next(), Some(1));
+    assert_eq!(values.next_back(), Some(4));
+    assert_eq!(values.len(), 2);
+    assert_eq!(values.next_back(), Some(3));
+    assert_eq!(values.next(), Some(2));
+    assert_eq!(values.next(), None);
+    assert_eq!(values.next_back(), None);
+}
+
+#[test]
+fn nth() {
+    let data = bytes(&[1, 2, 3, 4]);
+    let mut values = pod_iter::<u32>(&data).unwrap();
+    assert_eq!(values.nth(2), Some(3));
+    assert_eq!(values.len(), 1);
+    assert_eq!(values.nth(1), None);
+    assert_eq!(values.next(), None);
+    assert_eq!(pod_iter::<u32>(&data).unwrap().nth(usize::MAX), None);
+    assert_eq!(pod_iter::<u32>(&data).unwrap().last(), Some(4));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(pod_iter::<[u32; 0]>(&[0x01, 0x02]).unwrap_err(),
+               Error {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::ZeroSizedType,
+               });
+    assert_eq!(pod_iter_pedantic::<[u32; 0]>(&[]).unwrap_err().reason, ErrorReason::ZeroSizedType);
+
+    let values = pod_iter_permissive::<[u32; 0]>(&[0x01, 0x02]);
+    assert_eq!(values.len(), 0);
+    assert_eq!(values.remainder(), &[0x01, 0x02]);
+    assert_eq!(values.count(), 0);
+}
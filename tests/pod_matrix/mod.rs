@@ -0,0 +1,147 @@
+use safe_transmute::{ErrorReason, PodMatrix, Error, guarded_transmute_to_bytes_pod_many};
+
+
+#[test]
+fn too_short() {
+    let data = [0u32; 5];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(PodMatrix::<u32>::new(bytes, 2, 3).unwrap_err(),
+               Error {
+                   required: 24,
+                   actual: 20,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!(PodMatrix::<u32>::with_pitch(&bytes[..12], 2, 1, 16).unwrap_err(),
+               Error {
+                   required: 20,
+                   actual: 12,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+}
+
+#[test]
+fn too_much() {
+    let data = [0u32; 7];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(PodMatrix::<u32>::new(bytes, 2, 3).unwrap_err(),
+               Error {
+                   required: 24,
+                   actual: 28,
+                   reason: ErrorReason::InexactByteCount,
+               });
+}
+
+#[test]
+fn just_enough() {
+    let data = [1u32, 2, 3, 4, 5, 6];
+    let matrix = PodMatrix::<u32>::new(guarded_transmute_to_bytes_pod_many(&data), 2, 3).unwrap();
+    assert_eq!((matrix.row_count(), matrix.col_count(), matrix.pitch()), (2, 3, 12));
+    assert_eq!(matrix.row(0), Some(&[1, 2, 3][..]));
+    assert_eq!(matrix.row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(matrix.row(2), None);
+    assert_eq!(matrix.get(1, 0), Some(&4));
+    assert_eq!(matrix.get(0, 3), None);
+    assert_eq!(matrix[(1, 2)], 6);
+}
+
+#[test]
+fn pitched() {
+    let data = [1u16, 2, 0xFF, 0xFF, 3, 4, 0xFF, 0xFF, 5, 6, 0xFF, 0xFF];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+
+    // The last row's padding is optional
+    for len in [bytes.len() - 4, bytes.len()] {
+        let matrix = PodMatrix::<u16>::with_pitch(&bytes[..len], 3, 2, 8).unwrap();
+        assert_eq!(matrix.rows().collect::<Vec<_>>(), vec![&[1, 2][..], &[3, 4][..], &[5, 6][..]]);
+    }
+}
+
+#[test]
+fn empty() {
+    let matrix = PodMatrix::<u64>::new(&[], 0, 3).unwrap();
+    assert_eq!(matrix.rows().next(), None);
+
+    let matrix = PodMatrix::<u64>::new(&[], 3, 0).unwrap();
+    assert_eq!(matrix.rows().collect::<Vec<_>>(), vec![&[][..]; 3]);
+}
+
+#[test]
+fn zero_sized() {
+    let matrix = PodMatrix::<[u32; 0]>::new(&[], 1, 5).unwrap();
+    assert_eq!(matrix.row(0), Some(&[[]; 5][..]));
+    assert_eq!(matrix[(0, 4)], []);
+
+    let data = [0u8; 3];
+    let matrix = PodMatrix::<[u64; 0]>::new(&data[1..1], 2, 3).unwrap();
+    assert_eq!(matrix.rows().collect::<Vec<_>>(), vec![&[[]; 3][..]; 2]);
+}
+
+#[test]
+fn unaligned() {
+    let data = [0u32; 3];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(PodMatrix::<u16>::new(&bytes[1..9], 2, 2).unwrap_err(),
+               Error {
+                   required: 2,
+                   actual: 1,
+                   reason: ErrorReason::UnalignedMemory,
+               });
+}
+
+#[test]
+fn iterate() {
+    let data: Vec<u8> = (0..12).collect();
+    let matrix = PodMatrix::<u8>::new(&data, 4, 3).unwrap();
+
+    let mut rows = matrix.rows();
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows.next_back(), Some(&[9, 10, 11][..]));
+    assert_eq!(rows.nth(1), Some(&[3, 4, 5][..]));
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows.next(), Some(&[6, 7, 8][..]));
+    assert_eq!(rows.next(), None);
+}
+
+#[test]
+fn pitch_too_short() {
+    assert_eq!(PodMatrix::<u32>::with_pitch(&[], 0, 3, 8).unwrap_err(),
+               Error {
+                   required: 12,
+                   actual: 8,
+                   reason: ErrorReason::InvalidStride,
+               });
+}
+
+#[test]
+fn pitch_unaligned() {
+    assert_eq!(PodMatrix::<u32>::with_pitch(&[], 0, 1, 6).unwrap_err(),
+               Error {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::UnalignedMemory,
+               });
+}
+
+#[test]
+fn huge() {
+    let data = [0u16; 1];
+    let bytes = guarded_transmute_to_bytes_pod_many(&data);
+    assert_eq!(PodMatrix::<u16>::with_pitch(bytes, (1 << (usize::BITS - 1)) + 1, 1, 2).unwrap_err(),
+               Error {
+                   required: usize::MAX,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!(PodMatrix::<u16>::new(bytes, 2, usize::MAX / 2).unwrap_err().reason, ErrorReason::NotEnoughBytes);
+    assert_eq!(PodMatrix::<u16>::new(bytes, 1, usize::MAX).unwrap_err().required, usize::MAX);
+}
+
+#[test]
+fn row_overflow() {
+    assert_eq!(PodMatrix::<u32>::with_pitch(&[], 1, usize::MAX / 2, usize::MAX - 3).unwrap_err(),
+               Error {
+                   required: usize::MAX,
+                   actual: usize::MAX - 3,
+                   reason: ErrorReason::InvalidStride,
+               });
+}
@@ -0,0 +1,76 @@
+use safe_transmute::{DetailedError, ErrorReason, PartialRecord, PodReader, Error};
+use std::io::{self, ErrorKind, Cursor, Read};
+
+
+/// Reader returning at most 3 bytes at a time, to split records across reads.
+struct Trickle<R: Read>(R);
+
+impl<R: Read> Read for Trickle<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(3);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+fn records(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_ne_bytes()).collect()
+}
+
+
+#[test]
+fn empty() {
+    let mut reader = PodReader::<_, u32>::new(Cursor::new(vec![]));
+    assert_eq!(reader.next_batch().unwrap(), &[]);
+    assert_eq!(reader.read_record().unwrap(), None);
+    assert_eq!(reader.records_read(), 0);
+}
+
+#[test]
+fn batches() {
+    let mut reader = PodReader::<_, u32>::with_capacity(3, Cursor::new(records(&[1, 2, 3, 4, 5, 6, 7])));
+    assert_eq!(reader.next_batch().unwrap(), &[1, 2, 3]);
+    assert_eq!(reader.next_batch().unwrap(), &[4, 5, 6]);
+    assert_eq!(reader.next_batch().unwrap(), &[7]);
+    assert_eq!(reader.next_batch().unwrap(), &[]);
+    assert_eq!(reader.records_read(), 7);
+}
+
+#[test]
+fn split_records() {
+    let reader = PodReader::<_, u32>::with_capacity(2, Trickle(Cursor::new(records(&[0x01020304, 0x05060708, 0x090A0B0C]))));
+    assert_eq!(reader.collect::<io::Result<Vec<_>>>().unwrap(), vec![0x01020304, 0x05060708, 0x090A0B0C]);
+}
+
+#[test]
+fn partial_pedantic() {
+    let mut data = records(&[1, 2]);
+    data.push(0xFF);
+
+    let mut reader = PodReader::<_, u32>::new(Trickle(Cursor::new(data)));
+    assert_eq!(reader.read_record().unwrap(), Some(1));
+    assert_eq!(reader.read_record().unwrap(), Some(2));
+
+    let err = reader.read_record().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let err = err.get_ref().and_then(|e| e.downcast_ref::<DetailedError>()).unwrap();
+    assert_eq!(err.error,
+               Error {
+                   required: 32 / 8,
+                   actual: 1,
+                   reason: ErrorReason::InexactByteCount,
+               });
+    assert_eq!((err.type_name, err.offset, err.index, err.preview()), ("u32", Some(8), Some(2), &[0xFF][..]));
+
+    assert_eq!(reader.read_record().unwrap(), None);
+}
+
+#[test]
+fn partial_permissive() {
+    let mut data = records(&[1, 2]);
+    data.extend_from_slice(&[0xFF, 0xFF, 0xFF]);
+
+    let mut reader = PodReader::<_, u32>::new(Cursor::new(data));
+    reader.set_partial_record(PartialRecord::Permissive);
+    assert_eq!(reader.next_batch().unwrap(), &[1, 2]);
+    assert_eq!(reader.next_batch().unwrap(), &[]);
+}
@@ -0,0 +1,140 @@
+use safe_transmute::{PodTransmutable, Endianness, PodReader, PodWriter};
+use std::io::{self, ErrorKind, Cursor, Write};
+use std::cmp;
+
+
+/// Writer accepting `limit` bytes, and failing after that.
+struct FailAfter {
+    data: Vec<u8>,
+    limit: usize,
+}
+
+impl Write for FailAfter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = cmp::min(buf.len(), self.limit - self.data.len());
+        if len == 0 {
+            return Err(io::Error::other("full"));
+        }
+        self.data.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+
+#[test]
+fn native() {
+    let mut writer = PodWriter::new(vec![]);
+    writer.write_record(&0x01020304u32).unwrap();
+    writer.write_records(&[5u32, 6]).unwrap();
+    writer.write_records::<u32>(&[]).unwrap();
+
+    assert_eq!(writer.records_written(), 3);
+    assert_eq!(writer.bytes_written(), 12);
+    assert_eq!(writer.into_inner(),
+               [0x01020304u32.to_ne_bytes(), 5u32.to_ne_bytes(), 6u32.to_ne_bytes()].concat());
+}
+
+#[test]
+fn little_endian() {
+    let mut writer = PodWriter::new(vec![]);
+    writer.set_endianness(Endianness::Little);
+    writer.write_records(&[0x0102u16, 0x0304]).unwrap();
+    assert_eq!(writer.into_inner(), vec![0x02, 0x01, 0x04, 0x03]);
+}
+
+#[test]
+fn big_endian() {
+    let mut writer = PodWriter::new(vec![]);
+    writer.set_endianness(Endianness::Big);
+    writer.write_record(&0x01020304u32).unwrap();
+    writer.write_record(&0x05u8).unwrap();
+    assert_eq!(writer.into_inner(), vec![0x01, 0x02, 0x03, 0x04, 0x05]);
+}
+
+#[test]
+fn big_endian_many() {
+    let values: Vec<u64> = (0..5000).collect();
+
+    let mut writer = PodWriter::new(vec![]);
+    writer.set_endianness(Endianness::Big);
+    writer.write_records(&values).unwrap();
+
+    let bytes = writer.into_inner();
+    assert_eq!(bytes.len(), 5000 * 8);
+    assert!(bytes.chunks(8).zip(&values).all(|(b, v)| b == v.to_be_bytes()));
+}
+
+#[test]
+fn buffered() {
+    let mut writer = PodWriter::buffered(vec![]);
+    writer.write_records(&[1.5f32, 2.5, -3.0]).unwrap();
+    writer.flush().unwrap();
+
+    let bytes = writer.into_inner().into_inner().unwrap();
+    let read = PodReader::<_, f32>::new(Cursor::new(bytes)).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(read, vec![1.5, 2.5, -3.0]);
+}
+
+#[test]
+fn big_endian_struct() {
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Record {
+        tag: [u8; 2],
+        len: u16,
+        values: [u32; 2],
+    }
+
+    pod_transmutable!(Record { tag, len, values });
+
+    let mut writer = PodWriter::new(vec![]);
+    writer.set_endianness(Endianness::Big);
+    writer.write_record(&Record {
+            tag: [0x01, 0x02],
+            len: 0x0304,
+            values: [0x05060708, 0x090A0B0C],
+        })
+        .unwrap();
+    assert_eq!(writer.into_inner(), vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C]);
+}
+
+#[test]
+fn non_native_opaque() {
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Opaque(u32);
+
+    unsafe impl PodTransmutable for Opaque {}
+
+    let mut writer = PodWriter::new(vec![]);
+    writer.set_endianness(if Endianness::NATIVE == Endianness::Big { Endianness::Little } else { Endianness::Big });
+    assert_eq!(writer.write_record(&Opaque(1)).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(writer.records_written(), 0);
+    assert!(writer.into_inner().is_empty());
+}
+
+#[test]
+fn failed_partway() {
+    let mut writer = PodWriter::new(FailAfter {
+        data: vec![],
+        limit: 10,
+    });
+    writer.write_record(&1u16).unwrap();
+    assert_eq!(writer.write_records(&[2u32, 3, 4]).unwrap_err().kind(), ErrorKind::Other);
+    assert_eq!(writer.records_written(), 1 + 2);
+    assert_eq!(writer.bytes_written(), 10);
+    assert_eq!(writer.get_ref().data.len(), 10);
+
+    let mut writer = PodWriter::new(FailAfter {
+        data: vec![],
+        limit: 13,
+    });
+    writer.set_endianness(if Endianness::NATIVE == Endianness::Big { Endianness::Little } else { Endianness::Big });
+    assert_eq!(writer.write_records(&[1u64, 2]).unwrap_err().kind(), ErrorKind::Other);
+    assert_eq!(writer.records_written(), 1);
+    assert_eq!(writer.bytes_written(), 13);
+}
@@ -0,0 +1,34 @@
+use safe_transmute::{DetailedError, ErrorReason, Error, read_pod};
+use self::super::LeToNative;
+use std::io::{ErrorKind, Cursor};
+
+
+#[test]
+fn too_short() {
+    for bytes in &[&[][..], &[0x00][..], &[0x00, 0x00, 0x00][..]] {
+        let err = read_pod::<u32>(&mut Cursor::new(bytes)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        let err = err.get_ref().and_then(|e| e.downcast_ref::<DetailedError>()).unwrap();
+        assert_eq!(err.error,
+                   Error {
+                       required: 32 / 8,
+                       actual: bytes.len(),
+                       reason: ErrorReason::NotEnoughBytes,
+                   });
+        assert_eq!((err.type_name, err.offset, err.index, err.preview()), ("u32", Some(0), Some(0), *bytes));
+    }
+}
+
+#[test]
+fn just_enough() {
+    let mut stream = Cursor::new([0x00, 0x00, 0x00, 0x01].le_to_native::<u32>());
+    assert_eq!(read_pod::<u32>(&mut stream).unwrap(), 0x01000000);
+}
+
+#[test]
+fn too_much() {
+    let mut stream = Cursor::new([0x00, 0x01, 0x00, 0x02, 0x00].le_to_native::<u16>());
+    assert_eq!(read_pod::<u16>(&mut stream).unwrap(), 0x0100);
+    assert_eq!(read_pod::<u16>(&mut stream).unwrap(), 0x0200);
+    assert_eq!(stream.position(), 4);
+}
@@ -0,0 +1,31 @@
+use safe_transmute::{DetailedError, ErrorReason, Error, read_pod_into};
+use self::super::LeToNative;
+use std::io::{ErrorKind, Cursor};
+
+
+#[test]
+fn empty() {
+    read_pod_into::<u32>(&mut Cursor::new([]), &mut []).unwrap();
+}
+
+#[test]
+fn too_short() {
+    let mut values = [0u16; 3];
+    let err = read_pod_into(&mut Cursor::new([0x00, 0x01, 0x00]), &mut values).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    let err = err.get_ref().and_then(|e| e.downcast_ref::<DetailedError>()).unwrap();
+    assert_eq!(err.error,
+               Error {
+                   required: 3 * 16 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!((err.type_name, err.offset, err.index, err.preview()), ("u16", Some(2), Some(1), &[0x00][..]));
+}
+
+#[test]
+fn just_enough() {
+    let mut values = [0u16; 2];
+    read_pod_into(&mut Cursor::new([0x00, 0x01, 0x00, 0x02].le_to_native::<u16>()), &mut values).unwrap();
+    assert_eq!(values, [0x0100, 0x0200]);
+}
@@ -0,0 +1,123 @@
+use safe_transmute::{ErrorReason, StridedSlice, Error};
+
+
+/// 32-byte vertices: a position of 3 `f32`s at 0, an RGBA colour of 4 `u8`s at 12, and 16 bytes of padding.
+fn vertices(count: usize) -> Vec<u8> {
+    let mut ret = vec![];
+    for i in 0..count {
+        for c in 0..3 {
+            ret.extend_from_slice(&((i * 3 + c) as f32).to_ne_bytes());
+        }
+        ret.extend_from_slice(&[i as u8, 0x10, 0x20, 0xFF]);
+        ret.extend_from_slice(&[0; 16]);
+    }
+    ret
+}
+
+
+#[test]
+fn too_short() {
+    assert_eq!(StridedSlice::<u32>::new(&[], 0, 4).unwrap_err(),
+               Error {
+                   required: 32 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert_eq!(StridedSlice::<[u8; 4]>::new(&vertices(1)[..15], 12, 32).unwrap_err(),
+               Error {
+                   required: 16,
+                   actual: 15,
+                   reason: ErrorReason::NotEnoughBytes,
+               });
+    assert!(StridedSlice::<u32>::new_permissive(&[0; 3], 0, 4).unwrap().is_empty());
+}
+
+#[test]
+fn just_enough() {
+    let bytes = vertices(3);
+    let colors = StridedSlice::<[u8; 4]>::new_pedantic(&bytes, 12, 32).unwrap();
+    assert_eq!(colors.len(), 3);
+    assert_eq!(colors.get(0), Some([0, 0x10, 0x20, 0xFF]));
+    assert_eq!(colors.get(2), Some([2, 0x10, 0x20, 0xFF]));
+    assert_eq!(colors.get(3), None);
+
+    let positions = StridedSlice::<[f32; 3]>::new(&bytes, 0, 32).unwrap();
+    assert_eq!(positions.iter().collect::<Vec<_>>(), vec![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]);
+}
+
+#[test]
+fn too_much() {
+    let bytes = vertices(3);
+
+    // The last record only needs to be long enough for its element
+    let colors = StridedSlice::<[u8; 4]>::new(&bytes[..32 * 2 + 16], 12, 32).unwrap();
+    assert_eq!(colors.len(), 3);
+    assert_eq!(StridedSlice::<[u8; 4]>::new(&bytes[..32 * 2 + 15], 12, 32).unwrap().len(), 2);
+
+    assert_eq!(StridedSlice::<[u8; 4]>::new_pedantic(&bytes[..32 * 2 + 16], 12, 32).unwrap_err(),
+               Error {
+                   required: 32,
+                   actual: 32 * 2 + 16,
+                   reason: ErrorReason::InexactByteCount,
+               });
+    assert_eq!(StridedSlice::<[u8; 4]>::new_permissive(&bytes[..32 * 2 + 16], 12, 32).map(|s| s.len()), Ok(3));
+}
+
+#[test]
+fn unaligned() {
+    let mut bytes = vec![0xAA];
+    bytes.extend_from_slice(&vertices(2));
+    let y = StridedSlice::<f32>::new(&bytes[1..], 4, 32).unwrap();
+    assert_eq!(y.iter().collect::<Vec<_>>(), vec![1.0, 4.0]);
+}
+
+#[test]
+fn iterate() {
+    let bytes = vertices(5);
+    let red = StridedSlice::<u8>::new_pedantic(&bytes, 12, 32).unwrap();
+
+    let mut iter = red.iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.nth(1), Some(2));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(red.into_iter().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+    assert_eq!((&red).into_iter().nth(10), None);
+}
+
+#[test]
+fn element_wider_than_stride() {
+    assert_eq!(StridedSlice::<u64>::new_permissive(&[0; 16], 4, 8).unwrap_err(),
+               Error {
+                   required: 12,
+                   actual: 8,
+                   reason: ErrorReason::InvalidStride,
+               });
+    assert_eq!(StridedSlice::<u64>::new(&[0; 16], 4, 8).unwrap_err().reason, ErrorReason::InvalidStride);
+    assert_eq!(StridedSlice::<u64>::new_pedantic(&[0; 16], 4, 8).unwrap_err().reason, ErrorReason::InvalidStride);
+}
+
+#[test]
+fn zero_stride() {
+    assert_eq!(StridedSlice::<[u8; 0]>::new_permissive(&[0; 16], 0, 0).unwrap_err(),
+               Error {
+                   required: 1,
+                   actual: 0,
+                   reason: ErrorReason::InvalidStride,
+               });
+}
+
+#[test]
+fn offset_overflow() {
+    assert_eq!(StridedSlice::<u32>::new_permissive(&[0; 16], usize::MAX, 8).unwrap_err(),
+               Error {
+                   required: usize::MAX,
+                   actual: 8,
+                   reason: ErrorReason::InvalidStride,
+               });
+}
@@ -1,13 +1,49 @@
 #[cfg(target_endian = "big")]
 use std::mem::size_of;
+use std::ops::{DerefMut, Deref};
 
 
 /// Verify: http://play.integer32.com/?gist=4cd795d6f45898c876a754cd3f3c2aaa&version=stable
 trait LeToNative {
-    fn le_to_native<T: Sized>(self) -> Self;
+    type Output;
+
+    fn le_to_native<T: Sized>(self) -> Self::Output;
+}
+
+/// Byte array aligned for any primitive, so the guards' alignment checks always pass.
+#[derive(Debug)]
+#[repr(C, align(16))]
+struct AlignedBytes<const N: usize>([u8; N]);
+
+impl<const N: usize> Deref for AlignedBytes<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for AlignedBytes<N> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
 }
 
-impl<'a> LeToNative for &'a mut [u8] {
+impl<const N: usize> AsRef<[u8]> for AlignedBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> PartialEq<AlignedBytes<N>> for [u8] {
+    fn eq(&self, other: &AlignedBytes<N>) -> bool {
+        *self == other.0
+    }
+}
+
+impl LeToNative for &mut [u8] {
+    type Output = Self;
+
     #[cfg(target_endian = "little")]
     fn le_to_native<T: Sized>(self) -> Self {
         self
@@ -23,6 +59,8 @@ impl<'a> LeToNative for &'a mut [u8] {
 }
 
 impl LeToNative for Vec<u8> {
+    type Output = Self;
+
     #[cfg(target_endian = "little")]
     fn le_to_native<T: Sized>(self) -> Self {
         self
@@ -38,15 +76,17 @@ impl LeToNative for Vec<u8> {
 macro_rules! impl_le_to_native_array_u8 {
     ($n:expr) => {
         impl LeToNative for [u8; $n] {
+            type Output = AlignedBytes<$n>;
+
             #[cfg(target_endian = "little")]
-            fn le_to_native<T: Sized>(self) -> Self {
-                self
+            fn le_to_native<T: Sized>(self) -> AlignedBytes<$n> {
+                AlignedBytes(self)
             }
 
             #[cfg(target_endian = "big")]
-            fn le_to_native<T: Sized>(mut self) -> Self {
+            fn le_to_native<T: Sized>(mut self) -> AlignedBytes<$n> {
                 (&mut self[..]).le_to_native::<T>();
-                self
+                AlignedBytes(self)
             }
         }
     }
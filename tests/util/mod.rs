@@ -4,7 +4,7 @@ use std::{f32, f64};
 
 #[test]
 fn designalise_f32() {
-    assert_eq!(util::designalise_f32(12.34125121), 12.34125121);
+    assert_eq!(util::designalise_f32(12.341251), 12.341251);
     assert!(util::designalise_f32(f32::NAN).is_nan());
     // I'm not quite sure how to make an sNaN to test this so...
 }
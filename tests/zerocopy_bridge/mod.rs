@@ -0,0 +1,47 @@
+use safe_transmute::zerocopy::{guarded_transmute_to_bytes_pod_many_mut, guarded_transmute_to_bytes_pod_many, guarded_transmute_pod_many, guarded_transmute_pod};
+#[cfg(feature = "alloc")]
+use safe_transmute::zerocopy::guarded_transmute_pod_vec_pedantic;
+use zerocopy::{IntoBytes, FromBytes, Immutable};
+
+
+// The guards are shared with the bytemuck bridge and tested there, this checks the zerocopy bounds are satisfiable
+#[derive(Clone, Copy, Debug, PartialEq, FromBytes, IntoBytes, Immutable)]
+#[repr(C)]
+struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+const RED: Rgba = Rgba {
+    r: 0xFF,
+    g: 0,
+    b: 0,
+    a: 0xFF,
+};
+const BLUE: Rgba = Rgba {
+    r: 0,
+    g: 0,
+    b: 0xFF,
+    a: 0x80,
+};
+
+
+#[test]
+fn derived() {
+    let bytes = [0xFF, 0, 0, 0xFF, 0, 0, 0xFF, 0x80];
+    assert_eq!(guarded_transmute_pod::<Rgba>(&bytes), Ok(RED));
+    assert_eq!(guarded_transmute_pod_many::<Rgba>(&bytes), Ok(&[RED, BLUE][..]));
+
+    let mut colours = [RED, BLUE];
+    assert_eq!(guarded_transmute_to_bytes_pod_many(&colours), &bytes);
+    guarded_transmute_to_bytes_pod_many_mut(&mut colours)[4..].copy_from_slice(&[0xFF, 0, 0, 0xFF]);
+    assert_eq!(colours, [RED, RED]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    assert_eq!(guarded_transmute_pod_vec_pedantic::<Rgba>(vec![0, 0, 0xFF, 0x80]), Ok(vec![BLUE]));
+}
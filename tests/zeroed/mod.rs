@@ -0,0 +1,25 @@
+use safe_transmute::zeroed;
+use std::num::{NonZeroU64, NonZeroI8};
+use std::ptr;
+
+
+#[test]
+fn pod() {
+    assert_eq!(zeroed::<u8>(), 0);
+    assert_eq!(zeroed::<i32>(), 0);
+    assert_eq!(zeroed::<u128>(), 0);
+    assert_eq!(zeroed::<f64>(), 0.0);
+    assert_eq!(zeroed::<char>(), '\0');
+}
+
+#[test]
+fn nonzero() {
+    assert_eq!(zeroed::<Option<NonZeroU64>>(), None);
+    assert_eq!(zeroed::<Option<NonZeroI8>>(), None);
+}
+
+#[test]
+fn pointer() {
+    assert_eq!(zeroed::<*const u16>(), ptr::null());
+    assert_eq!(zeroed::<*mut f32>(), ptr::null_mut());
+}
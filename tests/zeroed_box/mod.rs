@@ -0,0 +1,19 @@
+use safe_transmute::zeroed_box;
+use std::num::NonZeroU16;
+
+
+#[test]
+fn pod() {
+    assert_eq!(*zeroed_box::<u64>(), 0);
+    assert_eq!(*zeroed_box::<f32>(), 0.0);
+}
+
+#[test]
+fn nonzero() {
+    assert_eq!(*zeroed_box::<Option<NonZeroU16>>(), None);
+}
+
+#[test]
+fn pointer() {
+    assert!(zeroed_box::<*const i64>().is_null());
+}
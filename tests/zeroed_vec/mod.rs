@@ -0,0 +1,25 @@
+use safe_transmute::{guarded_transmute_pod_many_permissive, zeroed_vec};
+use std::num::NonZeroU32;
+
+
+#[test]
+fn empty() {
+    assert_eq!(zeroed_vec::<u32>(0), vec![]);
+    assert_eq!(zeroed_vec::<u32>(0).capacity(), 0);
+}
+
+#[test]
+fn filled() {
+    let buf = zeroed_vec::<u32>(1024);
+    assert_eq!(buf.len(), 1024);
+    assert_eq!(buf.capacity(), 1024);
+    assert!(buf.iter().all(|&v| v == 0));
+
+    assert_eq!(zeroed_vec::<Option<NonZeroU32>>(3), vec![None, None, None]);
+}
+
+#[test]
+fn view_bytes() {
+    let buf = zeroed_vec::<u8>(9);
+    assert_eq!(guarded_transmute_pod_many_permissive::<u8>(&buf), &[0; 9]);
+}